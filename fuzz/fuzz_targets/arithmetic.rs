@@ -0,0 +1,103 @@
+#![no_main]
+
+// Runs every operator on every backing type. Inputs hitting a documented panic
+// (an indeterminate form or division by zero) are skipped for the panicking
+// operators, so any crash is an undocumented panic. The `checked_*` methods
+// and comparisons must never panic and run on every input.
+
+use arbitrary::Arbitrary;
+use intfinity::{DoubleInfiniteNumber, SingleInfiniteNumber};
+use libfuzzer_sys::fuzz_target;
+
+#[derive(Debug, Arbitrary)]
+enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Neg,
+}
+
+#[derive(Debug, Arbitrary)]
+enum Input {
+    I8(Op, DoubleInfiniteNumber<i8>, DoubleInfiniteNumber<i8>),
+    I16(Op, DoubleInfiniteNumber<i16>, DoubleInfiniteNumber<i16>),
+    I32(Op, DoubleInfiniteNumber<i32>, DoubleInfiniteNumber<i32>),
+    I64(Op, DoubleInfiniteNumber<i64>, DoubleInfiniteNumber<i64>),
+    I128(Op, DoubleInfiniteNumber<i128>, DoubleInfiniteNumber<i128>),
+    U128(Op, SingleInfiniteNumber<u128>, SingleInfiniteNumber<u128>),
+    U8(Op, SingleInfiniteNumber<u8>, SingleInfiniteNumber<u8>),
+    U16(Op, SingleInfiniteNumber<u16>, SingleInfiniteNumber<u16>),
+    U32(Op, SingleInfiniteNumber<u32>, SingleInfiniteNumber<u32>),
+    U64(Op, SingleInfiniteNumber<u64>, SingleInfiniteNumber<u64>),
+}
+
+fn double_documented_panic<T: PartialEq + Default>(op: &Op, a: &DoubleInfiniteNumber<T>, b: &DoubleInfiniteNumber<T>) -> bool {
+    let zero = |x: &DoubleInfiniteNumber<T>| matches!(x, DoubleInfiniteNumber::Finite(v) if *v == T::default());
+    let infinite = |x: &DoubleInfiniteNumber<T>| !matches!(x, DoubleInfiniteNumber::Finite(_));
+    match op {
+        Op::Add => matches!(
+            (a, b),
+            (DoubleInfiniteNumber::PosInfinity, DoubleInfiniteNumber::NegInfinity) | (DoubleInfiniteNumber::NegInfinity, DoubleInfiniteNumber::PosInfinity)
+        ),
+        Op::Sub => matches!(
+            (a, b),
+            (DoubleInfiniteNumber::PosInfinity, DoubleInfiniteNumber::PosInfinity) | (DoubleInfiniteNumber::NegInfinity, DoubleInfiniteNumber::NegInfinity)
+        ),
+        Op::Mul => (zero(a) && infinite(b)) || (infinite(a) && zero(b)),
+        Op::Div => zero(b) || (infinite(a) && infinite(b)),
+        Op::Neg => false,
+    }
+}
+
+fn single_documented_panic<T: PartialEq + Default + intfinity::traits::Unsigned>(op: &Op, a: &SingleInfiniteNumber<T>, b: &SingleInfiniteNumber<T>) -> bool {
+    let zero = |x: &SingleInfiniteNumber<T>| matches!(x, SingleInfiniteNumber::Finite(v) if *v == T::default());
+    let infinite = |x: &SingleInfiniteNumber<T>| matches!(x, SingleInfiniteNumber::Infinity);
+    match op {
+        Op::Mul => (zero(a) && infinite(b)) || (infinite(a) && zero(b)),
+        Op::Div => zero(b) || (infinite(a) && infinite(b)),
+        Op::Add | Op::Sub | Op::Neg => false,
+    }
+}
+
+macro_rules! run {
+    ($op:expr, $a:expr, $b:expr, $documented:ident, $neg:expr) => {{
+        let (op, a, b) = ($op, $a, $b);
+        let _ = (a.checked_add(b), a.checked_sub(b), a.checked_mul(b), a.checked_div(b));
+        let _ = (a.cmp(&b), a.indeterminate_cmp(&b));
+        if !$documented(&op, &a, &b) {
+            match op {
+                Op::Add => {
+                    let _ = (a + b, a.overflowing_add(b), a.saturating_add(b));
+                },
+                Op::Sub => {
+                    let _ = (a - b, a.overflowing_sub(b), a.saturating_sub(b));
+                },
+                Op::Mul => {
+                    let _ = (a * b, a.overflowing_mul(b), a.saturating_mul(b));
+                },
+                Op::Div => {
+                    let _ = (a / b, a.overflowing_div(b), a.saturating_div(b));
+                },
+                Op::Neg => {
+                    let _ = $neg(a);
+                },
+            }
+        }
+    }};
+}
+
+fuzz_target!(|input: Input| {
+    match input {
+        Input::I8(op, a, b) => run!(op, a, b, double_documented_panic, DoubleInfiniteNumber::negate_double_bounded_infinity),
+        Input::I16(op, a, b) => run!(op, a, b, double_documented_panic, DoubleInfiniteNumber::negate_double_bounded_infinity),
+        Input::I32(op, a, b) => run!(op, a, b, double_documented_panic, DoubleInfiniteNumber::negate_double_bounded_infinity),
+        Input::I64(op, a, b) => run!(op, a, b, double_documented_panic, DoubleInfiniteNumber::negate_double_bounded_infinity),
+        Input::I128(op, a, b) => run!(op, a, b, double_documented_panic, DoubleInfiniteNumber::negate_double_bounded_infinity),
+        Input::U128(op, a, b) => run!(op, a, b, single_documented_panic, core::convert::identity),
+        Input::U8(op, a, b) => run!(op, a, b, single_documented_panic, core::convert::identity),
+        Input::U16(op, a, b) => run!(op, a, b, single_documented_panic, core::convert::identity),
+        Input::U32(op, a, b) => run!(op, a, b, single_documented_panic, core::convert::identity),
+        Input::U64(op, a, b) => run!(op, a, b, single_documented_panic, core::convert::identity),
+    }
+});
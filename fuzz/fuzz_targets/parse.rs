@@ -0,0 +1,92 @@
+#![no_main]
+
+// Feeds raw input to every decoder and parser: `codec` and the fixed-size
+// byte encodings, the Postgres text syntax and serde through JSON. The first
+// byte picks the backing type. None of them may panic, and whatever they
+// accept must encode again and read back as the same value.
+
+use intfinity::postgres_text::{PgRange, PgValue};
+use intfinity::{DoubleInfiniteNumber, SingleInfiniteNumber};
+use libfuzzer_sys::fuzz_target;
+
+macro_rules! check_binary {
+    ($kind:ident<$t:ty>, $data:expr) => {{
+        let data: &[u8] = $data;
+        if let Ok((value, len)) = $kind::<$t>::decode(data) {
+            assert!(len <= data.len());
+            let mut buf = [0; $kind::<$t>::MAX_ENCODED_LEN];
+            let written = value.encode_into(&mut buf);
+            assert_eq!(written, value.encoded_len());
+            assert_eq!($kind::<$t>::decode(&buf[..written]), Ok((value, written)));
+        }
+        if let Some(fixed) = data.get(..core::mem::size_of::<$t>() + 1) {
+            let fixed = fixed.try_into().unwrap();
+            if let Some(value) = $kind::<$t>::from_le_bytes(fixed) {
+                assert_eq!(value.to_le_bytes(), fixed);
+            }
+            if let Some(value) = $kind::<$t>::from_be_bytes(fixed) {
+                assert_eq!(value.to_be_bytes(), fixed);
+            }
+        }
+    }};
+}
+
+macro_rules! check_json {
+    ($kind:ident<$t:ty>, $text:expr) => {{
+        if let Ok(value) = serde_json::from_str::<$kind<$t>>($text) {
+            let json = serde_json::to_string(&value).unwrap();
+            assert_eq!(serde_json::from_str::<$kind<$t>>(&json).unwrap(), value);
+        }
+    }};
+}
+
+macro_rules! check_postgres {
+    ($t:ty, $text:expr) => {{
+        let text: &str = $text;
+        if let Ok(value) = text.parse::<PgValue<$t>>() {
+            assert_eq!(value.to_string().parse::<PgValue<$t>>(), Ok(value));
+        }
+        if let Ok(range) = text.parse::<PgRange<$t>>() {
+            assert_eq!(range.to_string().parse::<PgRange<$t>>(), Ok(range));
+        }
+    }};
+}
+
+macro_rules! check_double {
+    ($t:ty, $data:expr) => {{
+        let data: &[u8] = $data;
+        check_binary!(DoubleInfiniteNumber<$t>, data);
+        if let Ok(text) = core::str::from_utf8(data) {
+            check_json!(DoubleInfiniteNumber<$t>, text);
+            check_postgres!($t, text);
+        }
+    }};
+}
+
+macro_rules! check_single {
+    ($t:ty, $data:expr) => {{
+        let data: &[u8] = $data;
+        check_binary!(SingleInfiniteNumber<$t>, data);
+        if let Ok(text) = core::str::from_utf8(data) {
+            check_json!(SingleInfiniteNumber<$t>, text);
+        }
+    }};
+}
+
+fuzz_target!(|input: &[u8]| {
+    let Some((&selector, data)) = input.split_first() else {
+        return;
+    };
+    match selector % 10 {
+        0 => check_double!(i8, data),
+        1 => check_double!(i16, data),
+        2 => check_double!(i32, data),
+        3 => check_double!(i64, data),
+        4 => check_double!(i128, data),
+        5 => check_single!(u8, data),
+        6 => check_single!(u16, data),
+        7 => check_single!(u32, data),
+        8 => check_single!(u64, data),
+        _ => check_single!(u128, data),
+    }
+});
@@ -0,0 +1,119 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Index, Member};
+
+/// Implements `Zero`, `Negate`, `CheckedNeg` and the other `Checked*` traits
+/// from `intfinity::traits` for a single-field newtype by delegating to the field.
+///
+/// With `#[intfinity(unsigned)]` the `Unsigned` marker is implemented instead
+/// of `Negate` and `CheckedNeg`, for newtypes usable in a `SingleInfiniteNumber`.
+#[proc_macro_derive(InfinityNumeric, attributes(intfinity))]
+pub fn derive_infinity_numeric(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let unsigned = is_unsigned(&input)?;
+
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => return Err(syn::Error::new_spanned(&input.ident, "InfinityNumeric can only be derived for structs")),
+    };
+    if fields.len() != 1 {
+        return Err(syn::Error::new_spanned(&input.ident, "InfinityNumeric needs a struct with exactly one field"));
+    }
+    let field = fields.iter().next().unwrap();
+    let inner = &field.ty;
+    let member = match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(Index::from(0)),
+    };
+    let wrap = |value: TokenStream2| match fields {
+        Fields::Named(_) => quote!(Self { #member: #value }),
+        _ => quote!(Self(#value)),
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let predicates = where_clause.map(|w| &w.predicates);
+    let traits = quote!(::intfinity::traits);
+
+    let zero = wrap(quote!(<#inner as #traits::Zero>::zero()));
+    let mut impls = quote! {
+        impl #impl_generics #traits::Zero for #name #ty_generics
+        where
+            #inner: #traits::Zero,
+            #predicates
+        {
+            fn zero() -> Self {
+                #zero
+            }
+        }
+    };
+
+    for (trait_name, method) in [("CheckedAdd", "checked_add"), ("CheckedSub", "checked_sub"), ("CheckedMul", "checked_mul"), ("CheckedDiv", "checked_div")] {
+        let trait_ident = syn::Ident::new(trait_name, proc_macro2::Span::call_site());
+        let method_ident = syn::Ident::new(method, proc_macro2::Span::call_site());
+        let result = wrap(quote!(value));
+        impls.extend(quote! {
+            impl #impl_generics #traits::#trait_ident for #name #ty_generics
+            where
+                #inner: #traits::#trait_ident,
+                #predicates
+            {
+                fn #method_ident(self, other: Self) -> ::core::option::Option<Self> {
+                    <#inner as #traits::#trait_ident>::#method_ident(self.#member, other.#member).map(|value| #result)
+                }
+            }
+        });
+    }
+
+    if unsigned {
+        impls.extend(quote! {
+            impl #impl_generics #traits::Unsigned for #name #ty_generics #where_clause {}
+        });
+    } else {
+        let negated = wrap(quote!(<#inner as #traits::Negate>::negate(self.#member)));
+        let checked_negated = wrap(quote!(value));
+        impls.extend(quote! {
+            impl #impl_generics #traits::Negate for #name #ty_generics
+            where
+                #inner: #traits::Negate,
+                #predicates
+            {
+                fn negate(self) -> Self {
+                    #negated
+                }
+            }
+
+            impl #impl_generics #traits::CheckedNeg for #name #ty_generics
+            where
+                #inner: #traits::CheckedNeg,
+                #predicates
+            {
+                fn checked_neg(self) -> ::core::option::Option<Self> {
+                    <#inner as #traits::CheckedNeg>::checked_neg(self.#member).map(|value| #checked_negated)
+                }
+            }
+        });
+    }
+
+    Ok(impls)
+}
+
+fn is_unsigned(input: &DeriveInput) -> syn::Result<bool> {
+    let mut unsigned = false;
+    for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("intfinity")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("unsigned") {
+                unsigned = true;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported intfinity attribute, expected `unsigned`"))
+            }
+        })?;
+    }
+    Ok(unsigned)
+}
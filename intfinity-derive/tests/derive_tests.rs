@@ -0,0 +1,59 @@
+use intfinity::traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedSub, Negate, Zero};
+use intfinity::{DoubleInfiniteNumber, SingleInfiniteNumber};
+use intfinity_derive::InfinityNumeric;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, InfinityNumeric)]
+struct Fixed32(i32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, InfinityNumeric)]
+#[intfinity(unsigned)]
+struct Money {
+    cents: u64,
+}
+
+impl std::ops::Add for Fixed32 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Fixed32(self.0 + other.0)
+    }
+}
+
+impl std::ops::Add for Money {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Money { cents: self.cents + other.cents }
+    }
+}
+
+#[test]
+fn test_tuple_newtype_delegates() {
+    assert_eq!(Fixed32::zero(), Fixed32(0));
+    assert!(Fixed32(0).is_zero());
+    assert_eq!(Fixed32(5).negate(), Fixed32(-5));
+    assert_eq!(Fixed32(5).checked_neg(), Some(Fixed32(-5)));
+    assert_eq!(Fixed32(i32::MIN).checked_neg(), None);
+    assert_eq!(Fixed32(5).checked_add(Fixed32(6)), Some(Fixed32(11)));
+    assert_eq!(Fixed32(i32::MIN).checked_sub(Fixed32(1)), None);
+    assert_eq!(Fixed32(i32::MAX).checked_mul(Fixed32(2)), None);
+    assert_eq!(Fixed32(7).checked_div(Fixed32(0)), None);
+}
+
+#[test]
+fn test_named_unsigned_newtype_delegates() {
+    assert_eq!(Money::zero(), Money { cents: 0 });
+    assert_eq!(Money { cents: 3 }.checked_sub(Money { cents: 5 }), None);
+    assert_eq!(Money { cents: 9 }.checked_div(Money { cents: 3 }), Some(Money { cents: 3 }));
+}
+
+#[test]
+fn test_newtypes_inside_infinite_numbers() {
+    let sum = DoubleInfiniteNumber::new(Fixed32(i32::MAX)) + DoubleInfiniteNumber::new(Fixed32(1));
+    assert_eq!(sum, DoubleInfiniteNumber::PosInfinity);
+    assert_eq!(DoubleInfiniteNumber::new(Fixed32(i32::MIN)).negate_double_bounded_infinity(), DoubleInfiniteNumber::PosInfinity);
+
+    let total = SingleInfiniteNumber::new(Money { cents: 250 }) + SingleInfiniteNumber::new(Money { cents: 50 });
+    assert_eq!(total, SingleInfiniteNumber::new(Money { cents: 300 }));
+    assert_eq!(SingleInfiniteNumber::new(Money { cents: u64::MAX }) + SingleInfiniteNumber::new(Money { cents: 1 }), SingleInfiniteNumber::Infinity);
+}
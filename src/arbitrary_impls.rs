@@ -0,0 +1,33 @@
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::intfinity::{SingleInfiniteNumber, DoubleInfiniteNumber};
+use crate::traits::Unsigned;
+
+// finite values are drawn four times as often as each infinity
+
+impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for DoubleInfiniteNumber<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(match u.int_in_range(0..=5u8)? {
+            0 => DoubleInfiniteNumber::NegInfinity,
+            1 => DoubleInfiniteNumber::PosInfinity,
+            _ => DoubleInfiniteNumber::Finite(T::arbitrary(u)?),
+        })
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        arbitrary::size_hint::and(<u8 as Arbitrary>::size_hint(depth), T::size_hint(depth))
+    }
+}
+
+impl<'a, T: Arbitrary<'a> + Unsigned> Arbitrary<'a> for SingleInfiniteNumber<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(match u.int_in_range(0..=4u8)? {
+            0 => SingleInfiniteNumber::Infinity,
+            _ => SingleInfiniteNumber::Finite(T::arbitrary(u)?),
+        })
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        arbitrary::size_hint::and(<u8 as Arbitrary>::size_hint(depth), T::size_hint(depth))
+    }
+}
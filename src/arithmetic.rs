@@ -0,0 +1,449 @@
+//! Method families mirroring the primitives' `checked_*`, `overflowing_*`,
+//! `saturating_*` and `wrapping_*`, and fused `mul_div`/`mul_add`. The
+//! operators promote finite overflow to infinity; these let the caller notice
+//! or avoid that promotion.
+
+use core::cmp::Ordering;
+use core::ops::{Add, Sub, Mul, Div};
+
+use crate::intfinity::{SingleInfiniteNumber, DoubleInfiniteNumber};
+use crate::traits::{Bounded, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Unsigned, WideningMul, Zero};
+
+/// What `inf - inf` gives for a `SingleInfiniteNumber`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InfiniteDifference {
+    /// `Infinity`, as the `-` operator does.
+    Infinity,
+    /// Panics, like `inf - inf` for a `DoubleInfiniteNumber`.
+    Indeterminate,
+}
+
+impl<T: Bounded> DoubleInfiniteNumber<T> {
+    pub const MAX_FINITE: Self = DoubleInfiniteNumber::Finite(T::MAX_VALUE);
+    pub const MIN_FINITE: Self = DoubleInfiniteNumber::Finite(T::MIN_VALUE);
+
+    // maps an infinity produced by overflow back to the nearest finite value
+    fn saturate(self) -> Self {
+        match self {
+            DoubleInfiniteNumber::PosInfinity => Self::MAX_FINITE,
+            DoubleInfiniteNumber::NegInfinity => Self::MIN_FINITE,
+            finite => finite,
+        }
+    }
+}
+
+impl<T: Bounded + Unsigned> SingleInfiniteNumber<T> {
+    pub const MAX_FINITE: Self = SingleInfiniteNumber::Finite(T::MAX_VALUE);
+    pub const MIN_FINITE: Self = SingleInfiniteNumber::Finite(T::MIN_VALUE);
+}
+
+impl<T> DoubleInfiniteNumber<T>
+where
+    T: Copy + Add<Output = T> + PartialOrd + Zero + CheckedAdd,
+{
+    /// `None` if finite operands overflow or for `+inf + (-inf)`.
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        match (self, other) {
+            (DoubleInfiniteNumber::Finite(a), DoubleInfiniteNumber::Finite(b)) => a.checked_add(b).map(DoubleInfiniteNumber::Finite),
+            (DoubleInfiniteNumber::PosInfinity, DoubleInfiniteNumber::NegInfinity) | (DoubleInfiniteNumber::NegInfinity, DoubleInfiniteNumber::PosInfinity) => None,
+            _ => Some(self + other),
+        }
+    }
+
+    /// The `+` result, and whether finite operands were promoted to infinity.
+    pub fn overflowing_add(self, other: Self) -> (Self, bool) {
+        let overflowed = match (self, other) {
+            (DoubleInfiniteNumber::Finite(a), DoubleInfiniteNumber::Finite(b)) => a.checked_add(b).is_none(),
+            _ => false,
+        };
+        (self + other, overflowed)
+    }
+
+    /// Like `+`, but finite operands clamp to `MAX_FINITE`/`MIN_FINITE`.
+    pub fn saturating_add(self, other: Self) -> Self
+    where
+        T: Bounded,
+    {
+        match self.overflowing_add(other) {
+            (result, true) => result.saturate(),
+            (result, false) => result,
+        }
+    }
+}
+
+impl<T> DoubleInfiniteNumber<T>
+where
+    T: Copy + Sub<Output = T> + PartialOrd + Zero + CheckedSub,
+{
+    /// `None` if finite operands overflow or for `inf - inf`.
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        match (self, other) {
+            (DoubleInfiniteNumber::Finite(a), DoubleInfiniteNumber::Finite(b)) => a.checked_sub(b).map(DoubleInfiniteNumber::Finite),
+            (DoubleInfiniteNumber::PosInfinity, DoubleInfiniteNumber::PosInfinity) | (DoubleInfiniteNumber::NegInfinity, DoubleInfiniteNumber::NegInfinity) => None,
+            _ => Some(self - other),
+        }
+    }
+
+    /// The `-` result, and whether finite operands were promoted to infinity.
+    pub fn overflowing_sub(self, other: Self) -> (Self, bool) {
+        let overflowed = match (self, other) {
+            (DoubleInfiniteNumber::Finite(a), DoubleInfiniteNumber::Finite(b)) => a.checked_sub(b).is_none(),
+            _ => false,
+        };
+        (self - other, overflowed)
+    }
+
+    /// Like `-`, but finite operands clamp to `MAX_FINITE`/`MIN_FINITE`.
+    pub fn saturating_sub(self, other: Self) -> Self
+    where
+        T: Bounded,
+    {
+        match self.overflowing_sub(other) {
+            (result, true) => result.saturate(),
+            (result, false) => result,
+        }
+    }
+}
+
+impl<T> DoubleInfiniteNumber<T>
+where
+    T: Copy + Mul<Output = T> + PartialOrd + Zero + CheckedMul,
+{
+    /// `None` if finite operands overflow or for `0 * inf`.
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        match (self, other) {
+            (DoubleInfiniteNumber::Finite(a), DoubleInfiniteNumber::Finite(b)) => a.checked_mul(b).map(DoubleInfiniteNumber::Finite),
+            (DoubleInfiniteNumber::Finite(a), _) | (_, DoubleInfiniteNumber::Finite(a)) if a.is_zero() => None,
+            _ => Some(self * other),
+        }
+    }
+
+    /// The `*` result, and whether finite operands were promoted to infinity.
+    pub fn overflowing_mul(self, other: Self) -> (Self, bool) {
+        let overflowed = match (self, other) {
+            (DoubleInfiniteNumber::Finite(a), DoubleInfiniteNumber::Finite(b)) => a.checked_mul(b).is_none(),
+            _ => false,
+        };
+        (self * other, overflowed)
+    }
+
+    /// Like `*`, but finite operands clamp to `MAX_FINITE`/`MIN_FINITE`.
+    pub fn saturating_mul(self, other: Self) -> Self
+    where
+        T: Bounded,
+    {
+        match self.overflowing_mul(other) {
+            (result, true) => result.saturate(),
+            (result, false) => result,
+        }
+    }
+}
+
+impl<T> DoubleInfiniteNumber<T>
+where
+    T: Copy + Div<Output = T> + PartialOrd + Zero + CheckedDiv,
+{
+    /// `None` if finite operands overflow, on division by zero or for `inf / inf`.
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        match (self, other) {
+            (_, DoubleInfiniteNumber::Finite(b)) if b.is_zero() => None,
+            (DoubleInfiniteNumber::Finite(a), DoubleInfiniteNumber::Finite(b)) => a.checked_div(b).map(DoubleInfiniteNumber::Finite),
+            (DoubleInfiniteNumber::Finite(_), _) | (_, DoubleInfiniteNumber::Finite(_)) => Some(self / other),
+            _ => None,
+        }
+    }
+
+    /// The `/` result, and whether finite operands were promoted to infinity.
+    pub fn overflowing_div(self, other: Self) -> (Self, bool) {
+        let overflowed = match (self, other) {
+            (DoubleInfiniteNumber::Finite(a), DoubleInfiniteNumber::Finite(b)) => !b.is_zero() && a.checked_div(b).is_none(),
+            _ => false,
+        };
+        (self / other, overflowed)
+    }
+
+    /// Like `/`, but finite operands clamp to `MAX_FINITE`/`MIN_FINITE`.
+    pub fn saturating_div(self, other: Self) -> Self
+    where
+        T: Bounded,
+    {
+        match self.overflowing_div(other) {
+            (result, true) => result.saturate(),
+            (result, false) => result,
+        }
+    }
+}
+
+impl<T> SingleInfiniteNumber<T>
+where
+    T: Copy + Add<Output = T> + PartialOrd + Unsigned + CheckedAdd + Zero,
+{
+    /// `None` if finite operands overflow.
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        match (self, other) {
+            (SingleInfiniteNumber::Finite(a), SingleInfiniteNumber::Finite(b)) => a.checked_add(b).map(SingleInfiniteNumber::Finite),
+            _ => Some(self + other),
+        }
+    }
+
+    /// The `+` result, and whether finite operands were promoted to infinity.
+    pub fn overflowing_add(self, other: Self) -> (Self, bool) {
+        let overflowed = match (self, other) {
+            (SingleInfiniteNumber::Finite(a), SingleInfiniteNumber::Finite(b)) => a.checked_add(b).is_none(),
+            _ => false,
+        };
+        (self + other, overflowed)
+    }
+
+    /// Like `+`, but finite operands clamp to `MAX_FINITE`.
+    pub fn saturating_add(self, other: Self) -> Self
+    where
+        T: Bounded,
+    {
+        match self.overflowing_add(other) {
+            (_, true) => Self::MAX_FINITE,
+            (result, false) => result,
+        }
+    }
+}
+
+impl<T> SingleInfiniteNumber<T>
+where
+    T: Copy + Sub<Output = T> + PartialOrd + Unsigned + CheckedSub + Zero,
+{
+    /// `None` where `-` would clamp to zero, i.e. when subtracting a larger
+    /// value or infinity from a finite value, and for `inf - inf`.
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        match (self, other) {
+            (SingleInfiniteNumber::Finite(a), SingleInfiniteNumber::Finite(b)) => a.checked_sub(b).map(SingleInfiniteNumber::Finite),
+            (SingleInfiniteNumber::Infinity, SingleInfiniteNumber::Finite(_)) => Some(SingleInfiniteNumber::Infinity),
+            _ => None,
+        }
+    }
+
+    /// The `-` result, and whether it was clamped to zero. Subtraction never
+    /// promotes to infinity.
+    pub fn overflowing_sub(self, other: Self) -> (Self, bool) {
+        let clamped = match (self, other) {
+            (SingleInfiniteNumber::Finite(a), SingleInfiniteNumber::Finite(b)) => a.checked_sub(b).is_none(),
+            (SingleInfiniteNumber::Finite(_), SingleInfiniteNumber::Infinity) => true,
+            _ => false,
+        };
+        (self - other, clamped)
+    }
+
+    /// Same as `-`, which already clamps at zero.
+    pub fn saturating_sub(self, other: Self) -> Self {
+        self - other
+    }
+
+    /// Distance between the two values. Against an infinity it is infinite,
+    /// including `inf` against `inf`, following `-`.
+    pub fn abs_diff(self, other: Self) -> Self {
+        match (self, other) {
+            (SingleInfiniteNumber::Finite(a), SingleInfiniteNumber::Finite(b)) => {
+                if a >= b {
+                    SingleInfiniteNumber::Finite(a - b)
+                } else {
+                    SingleInfiniteNumber::Finite(b - a)
+                }
+            },
+            _ => SingleInfiniteNumber::Infinity,
+        }
+    }
+
+    /// `-` with the result of `inf - inf` chosen by `policy`; everything else
+    /// still clamps at zero.
+    pub fn sub_with(self, other: Self, policy: InfiniteDifference) -> Self {
+        match (self, other, policy) {
+            (SingleInfiniteNumber::Infinity, SingleInfiniteNumber::Infinity, InfiniteDifference::Indeterminate) => {
+                panic!("indeterminate form: inf - inf")
+            },
+            _ => self - other,
+        }
+    }
+}
+
+impl<T> SingleInfiniteNumber<T>
+where
+    T: Copy + Mul<Output = T> + PartialOrd + Unsigned + CheckedMul + Zero,
+{
+    /// `None` if finite operands overflow or for `0 * inf`.
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        match (self, other) {
+            (SingleInfiniteNumber::Finite(a), SingleInfiniteNumber::Finite(b)) => a.checked_mul(b).map(SingleInfiniteNumber::Finite),
+            (SingleInfiniteNumber::Finite(a), _) | (_, SingleInfiniteNumber::Finite(a)) if a.is_zero() => None,
+            _ => Some(self * other),
+        }
+    }
+
+    /// The `*` result, and whether finite operands were promoted to infinity.
+    pub fn overflowing_mul(self, other: Self) -> (Self, bool) {
+        let overflowed = match (self, other) {
+            (SingleInfiniteNumber::Finite(a), SingleInfiniteNumber::Finite(b)) => a.checked_mul(b).is_none(),
+            _ => false,
+        };
+        (self * other, overflowed)
+    }
+
+    /// Like `*`, but finite operands clamp to `MAX_FINITE`.
+    pub fn saturating_mul(self, other: Self) -> Self
+    where
+        T: Bounded,
+    {
+        match self.overflowing_mul(other) {
+            (_, true) => Self::MAX_FINITE,
+            (result, false) => result,
+        }
+    }
+}
+
+impl<T> SingleInfiniteNumber<T>
+where
+    T: Copy + Div<Output = T> + PartialOrd + Unsigned + CheckedDiv + Zero,
+{
+    /// `None` on division by zero or for `inf / inf`.
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        match (self, other) {
+            (_, SingleInfiniteNumber::Finite(b)) if b.is_zero() => None,
+            (SingleInfiniteNumber::Finite(a), SingleInfiniteNumber::Finite(b)) => a.checked_div(b).map(SingleInfiniteNumber::Finite),
+            (SingleInfiniteNumber::Infinity, SingleInfiniteNumber::Infinity) => None,
+            _ => Some(self / other),
+        }
+    }
+
+    /// The `/` result and `false`: unsigned division cannot overflow.
+    pub fn overflowing_div(self, other: Self) -> (Self, bool) {
+        (self / other, false)
+    }
+
+    /// Same as `/`, which cannot overflow.
+    pub fn saturating_div(self, other: Self) -> Self {
+        self / other
+    }
+}
+
+impl<T> DoubleInfiniteNumber<T>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T> + Div<Output = T> + PartialOrd + Zero + CheckedAdd + CheckedMul + CheckedDiv + WideningMul,
+{
+    /// `self * b / c` with the product kept at double width, so only a final
+    /// result out of range becomes infinite. Infinite operands follow `*` and
+    /// `/`. Panics on division by zero.
+    pub fn mul_div(self, b: Self, c: Self) -> Self {
+        match (self, b, c) {
+            (_, _, DoubleInfiniteNumber::Finite(z)) if z.is_zero() => panic!("division by zero"),
+            (DoubleInfiniteNumber::Finite(x), DoubleInfiniteNumber::Finite(y), DoubleInfiniteNumber::Finite(z)) => Self::from_wide(x.wide_mul_div(y, z)),
+            // a finite product over an infinity
+            (DoubleInfiniteNumber::Finite(_), DoubleInfiniteNumber::Finite(_), _) => DoubleInfiniteNumber::Finite(T::zero()),
+            _ => self * b / c,
+        }
+    }
+
+    /// `self * b + c` with the product kept at double width, so only a final
+    /// result out of range becomes infinite. Infinite operands follow `*` and `+`.
+    pub fn mul_add(self, b: Self, c: Self) -> Self {
+        match (self, b, c) {
+            (DoubleInfiniteNumber::Finite(x), DoubleInfiniteNumber::Finite(y), DoubleInfiniteNumber::Finite(z)) => Self::from_wide(x.wide_mul_add(y, z)),
+            (DoubleInfiniteNumber::Finite(_), DoubleInfiniteNumber::Finite(_), _) => c,
+            _ => self * b + c,
+        }
+    }
+
+    fn from_wide(result: Result<T, Ordering>) -> Self {
+        match result {
+            Ok(value) => DoubleInfiniteNumber::Finite(value),
+            Err(Ordering::Greater) => DoubleInfiniteNumber::PosInfinity,
+            Err(_) => DoubleInfiniteNumber::NegInfinity,
+        }
+    }
+}
+
+impl<T> SingleInfiniteNumber<T>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T> + Div<Output = T> + PartialOrd + Unsigned + Zero + CheckedAdd + CheckedMul + CheckedDiv + WideningMul,
+{
+    /// `self * b / c` with the product kept at double width, so only a final
+    /// result out of range becomes infinite. Infinite operands follow `*` and
+    /// `/`. Panics on division by zero.
+    pub fn mul_div(self, b: Self, c: Self) -> Self {
+        match (self, b, c) {
+            (_, _, SingleInfiniteNumber::Finite(z)) if z.is_zero() => panic!("division by zero"),
+            (SingleInfiniteNumber::Finite(x), SingleInfiniteNumber::Finite(y), SingleInfiniteNumber::Finite(z)) => {
+                x.wide_mul_div(y, z).map_or(SingleInfiniteNumber::Infinity, SingleInfiniteNumber::Finite)
+            },
+            (SingleInfiniteNumber::Finite(_), SingleInfiniteNumber::Finite(_), _) => SingleInfiniteNumber::Finite(T::zero()),
+            _ => self * b / c,
+        }
+    }
+
+    /// `self * b + c` with the product kept at double width, so only a final
+    /// result out of range becomes infinite. Infinite operands follow `*` and `+`.
+    pub fn mul_add(self, b: Self, c: Self) -> Self {
+        match (self, b, c) {
+            (SingleInfiniteNumber::Finite(x), SingleInfiniteNumber::Finite(y), SingleInfiniteNumber::Finite(z)) => {
+                x.wide_mul_add(y, z).map_or(SingleInfiniteNumber::Infinity, SingleInfiniteNumber::Finite)
+            },
+            (SingleInfiniteNumber::Finite(_), SingleInfiniteNumber::Finite(_), _) => c,
+            _ => self * b + c,
+        }
+    }
+}
+
+// `wrapping_*` wraps finite results like the primitives; any infinite operand
+// gives what the operator gives, so infinities stay sticky and indeterminate
+// forms still panic
+macro_rules! impl_wrapping {
+    ($kind:ident: $($t:ty),*) => {
+        $(
+            impl $kind<$t> {
+                pub fn wrapping_add(self, other: Self) -> Self {
+                    match (self, other) {
+                        ($kind::Finite(a), $kind::Finite(b)) => $kind::Finite(a.wrapping_add(b)),
+                        _ => self + other,
+                    }
+                }
+
+                pub fn wrapping_sub(self, other: Self) -> Self {
+                    match (self, other) {
+                        ($kind::Finite(a), $kind::Finite(b)) => $kind::Finite(a.wrapping_sub(b)),
+                        _ => self - other,
+                    }
+                }
+
+                pub fn wrapping_mul(self, other: Self) -> Self {
+                    match (self, other) {
+                        ($kind::Finite(a), $kind::Finite(b)) => $kind::Finite(a.wrapping_mul(b)),
+                        _ => self * other,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_wrapping!(DoubleInfiniteNumber: i8, i16, i32, i64, i128);
+impl_wrapping!(SingleInfiniteNumber: u8, u16, u32, u64, u128);
+
+// subtracts into the next wider signed type, where the difference of two
+// finite values always fits
+macro_rules! impl_signed_sub {
+    ($u:ty => $i:ty) => {
+        impl SingleInfiniteNumber<$u> {
+            /// `self - other` without clamping. Panics for `inf - inf`.
+            pub fn signed_sub(self, other: Self) -> DoubleInfiniteNumber<$i> {
+                match (self, other) {
+                    (SingleInfiniteNumber::Finite(a), SingleInfiniteNumber::Finite(b)) => DoubleInfiniteNumber::Finite(a as $i - b as $i),
+                    (SingleInfiniteNumber::Infinity, SingleInfiniteNumber::Infinity) => panic!("indeterminate form: inf - inf"),
+                    (SingleInfiniteNumber::Infinity, _) => DoubleInfiniteNumber::PosInfinity,
+                    (_, SingleInfiniteNumber::Infinity) => DoubleInfiniteNumber::NegInfinity,
+                }
+            }
+        }
+    };
+}
+
+impl_signed_sub!(u8 => i16);
+impl_signed_sub!(u16 => i32);
+impl_signed_sub!(u32 => i64);
+impl_signed_sub!(u64 => i128);
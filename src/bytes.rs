@@ -0,0 +1,185 @@
+//! Fixed-size byte encoding, stable across platforms.
+//!
+//! A value of `N`-byte backing type encodes to `N + 1` bytes: a tag byte,
+//! then the finite value in the requested byte order, or `N` zero bytes for
+//! an infinity. Tags are `0` for finite, `1` for `PosInfinity` (or `Infinity`)
+//! and `2` for `NegInfinity`, so all-zero bytes decode to `Finite(0)`.
+//! Decoding returns `None` for an unknown tag or an infinity with a non-zero
+//! payload.
+//!
+//! The `Packed*` types hold the little-endian encoding in a `#[repr(C)]`
+//! struct with alignment 1, for memory-mapped tables. With the `bytemuck` or
+//! `zerocopy` feature they implement those crates' casting traits.
+
+use crate::intfinity::{SingleInfiniteNumber, DoubleInfiniteNumber};
+
+// shared with `codec`
+pub(crate) const FINITE: u8 = 0;
+pub(crate) const POS_INFINITY: u8 = 1;
+pub(crate) const NEG_INFINITY: u8 = 2;
+
+macro_rules! impl_double_bytes {
+    ($t:ty, $n:literal, $packed:ident) => {
+        impl DoubleInfiniteNumber<$t> {
+            pub fn to_le_bytes(self) -> [u8; $n + 1] {
+                self.encode_fixed(<$t>::to_le_bytes)
+            }
+
+            pub fn to_be_bytes(self) -> [u8; $n + 1] {
+                self.encode_fixed(<$t>::to_be_bytes)
+            }
+
+            pub fn from_le_bytes(bytes: [u8; $n + 1]) -> Option<Self> {
+                Self::decode_fixed(bytes, <$t>::from_le_bytes)
+            }
+
+            pub fn from_be_bytes(bytes: [u8; $n + 1]) -> Option<Self> {
+                Self::decode_fixed(bytes, <$t>::from_be_bytes)
+            }
+
+            fn encode_fixed(self, value_bytes: fn($t) -> [u8; $n]) -> [u8; $n + 1] {
+                let mut bytes = [0; $n + 1];
+                match self {
+                    DoubleInfiniteNumber::Finite(val) => bytes[1..].copy_from_slice(&value_bytes(val)),
+                    DoubleInfiniteNumber::PosInfinity => bytes[0] = POS_INFINITY,
+                    DoubleInfiniteNumber::NegInfinity => bytes[0] = NEG_INFINITY,
+                }
+                bytes
+            }
+
+            fn decode_fixed(bytes: [u8; $n + 1], from_bytes: fn([u8; $n]) -> $t) -> Option<Self> {
+                let mut payload = [0; $n];
+                payload.copy_from_slice(&bytes[1..]);
+                match bytes[0] {
+                    FINITE => Some(DoubleInfiniteNumber::Finite(from_bytes(payload))),
+                    POS_INFINITY if payload == [0; $n] => Some(DoubleInfiniteNumber::PosInfinity),
+                    NEG_INFINITY if payload == [0; $n] => Some(DoubleInfiniteNumber::NegInfinity),
+                    _ => None,
+                }
+            }
+        }
+
+        #[doc = concat!("`DoubleInfiniteNumber<", stringify!($t), ">` in its little-endian byte encoding.")]
+        #[repr(C)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+        #[cfg_attr(
+            feature = "zerocopy",
+            derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::KnownLayout, zerocopy::Immutable, zerocopy::Unaligned)
+        )]
+        pub struct $packed {
+            tag: u8,
+            value: [u8; $n],
+        }
+
+        impl $packed {
+            pub fn new(value: DoubleInfiniteNumber<$t>) -> Self {
+                let bytes = value.to_le_bytes();
+                let mut packed = $packed { tag: bytes[0], value: [0; $n] };
+                packed.value.copy_from_slice(&bytes[1..]);
+                packed
+            }
+
+            /// `None` if the bytes are not a valid encoding.
+            pub fn get(self) -> Option<DoubleInfiniteNumber<$t>> {
+                let mut bytes = [self.tag; $n + 1];
+                bytes[1..].copy_from_slice(&self.value);
+                DoubleInfiniteNumber::<$t>::from_le_bytes(bytes)
+            }
+        }
+
+        impl From<DoubleInfiniteNumber<$t>> for $packed {
+            fn from(value: DoubleInfiniteNumber<$t>) -> Self {
+                $packed::new(value)
+            }
+        }
+    };
+}
+
+macro_rules! impl_single_bytes {
+    ($t:ty, $n:literal, $packed:ident) => {
+        impl SingleInfiniteNumber<$t> {
+            pub fn to_le_bytes(self) -> [u8; $n + 1] {
+                self.encode_fixed(<$t>::to_le_bytes)
+            }
+
+            pub fn to_be_bytes(self) -> [u8; $n + 1] {
+                self.encode_fixed(<$t>::to_be_bytes)
+            }
+
+            pub fn from_le_bytes(bytes: [u8; $n + 1]) -> Option<Self> {
+                Self::decode_fixed(bytes, <$t>::from_le_bytes)
+            }
+
+            pub fn from_be_bytes(bytes: [u8; $n + 1]) -> Option<Self> {
+                Self::decode_fixed(bytes, <$t>::from_be_bytes)
+            }
+
+            fn encode_fixed(self, value_bytes: fn($t) -> [u8; $n]) -> [u8; $n + 1] {
+                let mut bytes = [0; $n + 1];
+                match self {
+                    SingleInfiniteNumber::Finite(val) => bytes[1..].copy_from_slice(&value_bytes(val)),
+                    SingleInfiniteNumber::Infinity => bytes[0] = POS_INFINITY,
+                }
+                bytes
+            }
+
+            fn decode_fixed(bytes: [u8; $n + 1], from_bytes: fn([u8; $n]) -> $t) -> Option<Self> {
+                let mut payload = [0; $n];
+                payload.copy_from_slice(&bytes[1..]);
+                match bytes[0] {
+                    FINITE => Some(SingleInfiniteNumber::Finite(from_bytes(payload))),
+                    POS_INFINITY if payload == [0; $n] => Some(SingleInfiniteNumber::Infinity),
+                    _ => None,
+                }
+            }
+        }
+
+        #[doc = concat!("`SingleInfiniteNumber<", stringify!($t), ">` in its little-endian byte encoding.")]
+        #[repr(C)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+        #[cfg_attr(
+            feature = "zerocopy",
+            derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::KnownLayout, zerocopy::Immutable, zerocopy::Unaligned)
+        )]
+        pub struct $packed {
+            tag: u8,
+            value: [u8; $n],
+        }
+
+        impl $packed {
+            pub fn new(value: SingleInfiniteNumber<$t>) -> Self {
+                let bytes = value.to_le_bytes();
+                let mut packed = $packed { tag: bytes[0], value: [0; $n] };
+                packed.value.copy_from_slice(&bytes[1..]);
+                packed
+            }
+
+            /// `None` if the bytes are not a valid encoding.
+            pub fn get(self) -> Option<SingleInfiniteNumber<$t>> {
+                let mut bytes = [self.tag; $n + 1];
+                bytes[1..].copy_from_slice(&self.value);
+                SingleInfiniteNumber::<$t>::from_le_bytes(bytes)
+            }
+        }
+
+        impl From<SingleInfiniteNumber<$t>> for $packed {
+            fn from(value: SingleInfiniteNumber<$t>) -> Self {
+                $packed::new(value)
+            }
+        }
+    };
+}
+
+impl_double_bytes!(i8, 1, PackedI8);
+impl_double_bytes!(i16, 2, PackedI16);
+impl_double_bytes!(i32, 4, PackedI32);
+impl_double_bytes!(i64, 8, PackedI64);
+impl_double_bytes!(i128, 16, PackedI128);
+
+impl_single_bytes!(u8, 1, PackedU8);
+impl_single_bytes!(u16, 2, PackedU16);
+impl_single_bytes!(u32, 4, PackedU32);
+impl_single_bytes!(u64, 8, PackedU64);
+impl_single_bytes!(u128, 16, PackedU128);
@@ -0,0 +1,187 @@
+//! Variable-length wire format.
+//!
+//! Every value starts with a tag byte: `0` for finite, `1` for `PosInfinity`
+//! (or `Infinity`), `2` for `NegInfinity`. Infinities end there. A finite
+//! value follows as an unsigned LEB128 varint, zigzag-mapped first for signed
+//! types so small magnitudes of either sign stay short. The tags match those
+//! in [`bytes`](crate::bytes).
+
+use crate::bytes::{FINITE, NEG_INFINITY, POS_INFINITY};
+use crate::intfinity::{SingleInfiniteNumber, DoubleInfiniteNumber};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The input ended inside a value.
+    UnexpectedEnd,
+    InvalidTag(u8),
+    /// The varint does not fit in the backing type.
+    Overflow,
+}
+
+impl core::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DecodeError::UnexpectedEnd => write!(f, "input ended inside an encoded value"),
+            DecodeError::InvalidTag(tag) => write!(f, "invalid tag byte {}", tag),
+            DecodeError::Overflow => write!(f, "encoded value does not fit in the backing type"),
+        }
+    }
+}
+
+impl core::error::Error for DecodeError {}
+
+fn varint_len(mut value: u128) -> usize {
+    let mut len = 1;
+    while value >= 0x80 {
+        value >>= 7;
+        len += 1;
+    }
+    len
+}
+
+fn write_varint(mut value: u128, buf: &mut [u8]) -> usize {
+    let mut i = 0;
+    while value >= 0x80 {
+        buf[i] = (value as u8) | 0x80;
+        value >>= 7;
+        i += 1;
+    }
+    buf[i] = value as u8;
+    i + 1
+}
+
+fn read_varint(buf: &[u8]) -> Result<(u128, usize), DecodeError> {
+    let mut value: u128 = 0;
+    for (i, &byte) in buf.iter().enumerate() {
+        let shift = 7 * i as u32;
+        let bits = (byte & 0x7f) as u128;
+        if shift >= 128 || (bits << shift) >> shift != bits {
+            return Err(DecodeError::Overflow);
+        }
+        value |= bits << shift;
+        if byte & 0x80 == 0 {
+            return Ok((value, i + 1));
+        }
+    }
+    Err(DecodeError::UnexpectedEnd)
+}
+
+fn zigzag(value: i128) -> u128 {
+    ((value << 1) ^ (value >> 127)) as u128
+}
+
+fn unzigzag(value: u128) -> i128 {
+    ((value >> 1) as i128) ^ -((value & 1) as i128)
+}
+
+fn check_len(needed: usize, buf: &[u8]) {
+    if buf.len() < needed {
+        panic!("buffer too small for the encoded value")
+    }
+}
+
+macro_rules! impl_double_codec {
+    ($($t:ty),*) => {
+        $(
+            impl DoubleInfiniteNumber<$t> {
+                /// Longest encoding of any value.
+                pub const MAX_ENCODED_LEN: usize = 1 + (<$t>::BITS as usize).div_ceil(7);
+
+                pub fn encoded_len(self) -> usize {
+                    match self {
+                        DoubleInfiniteNumber::Finite(val) => 1 + varint_len(zigzag(val as i128)),
+                        _ => 1,
+                    }
+                }
+
+                /// Returns the number of bytes written. Panics if `buf` is
+                /// shorter than `encoded_len()`.
+                pub fn encode_into(self, buf: &mut [u8]) -> usize {
+                    check_len(self.encoded_len(), buf);
+                    match self {
+                        DoubleInfiniteNumber::Finite(val) => {
+                            buf[0] = FINITE;
+                            1 + write_varint(zigzag(val as i128), &mut buf[1..])
+                        }
+                        DoubleInfiniteNumber::PosInfinity => {
+                            buf[0] = POS_INFINITY;
+                            1
+                        }
+                        DoubleInfiniteNumber::NegInfinity => {
+                            buf[0] = NEG_INFINITY;
+                            1
+                        }
+                    }
+                }
+
+                /// Decodes a value from the front of `buf`, returning it with
+                /// the number of bytes read.
+                pub fn decode(buf: &[u8]) -> Result<(Self, usize), DecodeError> {
+                    match buf.first() {
+                        None => Err(DecodeError::UnexpectedEnd),
+                        Some(&FINITE) => {
+                            let (raw, len) = read_varint(&buf[1..])?;
+                            let val = <$t>::try_from(unzigzag(raw)).map_err(|_| DecodeError::Overflow)?;
+                            Ok((DoubleInfiniteNumber::Finite(val), 1 + len))
+                        }
+                        Some(&POS_INFINITY) => Ok((DoubleInfiniteNumber::PosInfinity, 1)),
+                        Some(&NEG_INFINITY) => Ok((DoubleInfiniteNumber::NegInfinity, 1)),
+                        Some(&tag) => Err(DecodeError::InvalidTag(tag)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_single_codec {
+    ($($t:ty),*) => {
+        $(
+            impl SingleInfiniteNumber<$t> {
+                /// Longest encoding of any value.
+                pub const MAX_ENCODED_LEN: usize = 1 + (<$t>::BITS as usize).div_ceil(7);
+
+                pub fn encoded_len(self) -> usize {
+                    match self {
+                        SingleInfiniteNumber::Finite(val) => 1 + varint_len(val as u128),
+                        SingleInfiniteNumber::Infinity => 1,
+                    }
+                }
+
+                /// Returns the number of bytes written. Panics if `buf` is
+                /// shorter than `encoded_len()`.
+                pub fn encode_into(self, buf: &mut [u8]) -> usize {
+                    check_len(self.encoded_len(), buf);
+                    match self {
+                        SingleInfiniteNumber::Finite(val) => {
+                            buf[0] = FINITE;
+                            1 + write_varint(val as u128, &mut buf[1..])
+                        }
+                        SingleInfiniteNumber::Infinity => {
+                            buf[0] = POS_INFINITY;
+                            1
+                        }
+                    }
+                }
+
+                /// Decodes a value from the front of `buf`, returning it with
+                /// the number of bytes read. A `NegInfinity` tag is invalid.
+                pub fn decode(buf: &[u8]) -> Result<(Self, usize), DecodeError> {
+                    match buf.first() {
+                        None => Err(DecodeError::UnexpectedEnd),
+                        Some(&FINITE) => {
+                            let (raw, len) = read_varint(&buf[1..])?;
+                            let val = <$t>::try_from(raw).map_err(|_| DecodeError::Overflow)?;
+                            Ok((SingleInfiniteNumber::Finite(val), 1 + len))
+                        }
+                        Some(&POS_INFINITY) => Ok((SingleInfiniteNumber::Infinity, 1)),
+                        Some(&tag) => Err(DecodeError::InvalidTag(tag)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_double_codec!(i8, i16, i32, i64, i128);
+impl_single_codec!(u8, u16, u32, u64, u128);
@@ -0,0 +1,163 @@
+use core::ops::{Index, IndexMut};
+
+use crate::intfinity::{SingleInfiniteNumber, DoubleInfiniteNumber};
+use crate::traits::{Infinite, Unsigned, Zero};
+
+/// Relaxation steps used by dynamic programming, built on the derived `Ord`.
+pub trait Relax: Ord + Sized {
+    /// Replaces `self` with `other` if it is smaller, returning whether it did.
+    fn min_assign(&mut self, other: Self) -> bool {
+        if other < *self {
+            *self = other;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Replaces `self` with `other` if it is greater, returning whether it did.
+    fn max_assign(&mut self, other: Self) -> bool {
+        if other > *self {
+            *self = other;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl<T: Ord> Relax for DoubleInfiniteNumber<T> {}
+
+impl<T: Ord + Unsigned> Relax for SingleInfiniteNumber<T> {}
+
+/// `rows x cols` table backed by a caller-provided row-major slice, with
+/// every cell starting at infinity.
+#[derive(Debug, PartialEq)]
+pub struct DpTable<'a, N> {
+    cells: &'a mut [N],
+    rows: usize,
+    cols: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EditCosts<N> {
+    pub insert: N,
+    pub delete: N,
+    pub substitute: N,
+}
+
+impl<'a, N> DpTable<'a, N>
+where
+    N: Infinite,
+{
+    pub fn new(cells: &'a mut [N], rows: usize, cols: usize) -> Self {
+        if cells.len() != rows * cols {
+            panic!("slice length does not match table dimensions")
+        }
+        cells.fill(N::infinity());
+        DpTable { cells, rows, cols }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn as_slice(&self) -> &[N] {
+        self.cells
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> N {
+        self[(row, col)]
+    }
+}
+
+impl<N> Index<(usize, usize)> for DpTable<'_, N> {
+    type Output = N;
+
+    fn index(&self, (row, col): (usize, usize)) -> &N {
+        if col >= self.cols {
+            panic!("column index out of bounds")
+        }
+        &self.cells[row * self.cols + col]
+    }
+}
+
+impl<N> IndexMut<(usize, usize)> for DpTable<'_, N> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut N {
+        if col >= self.cols {
+            panic!("column index out of bounds")
+        }
+        &mut self.cells[row * self.cols + col]
+    }
+}
+
+/// Weighted edit distance between `a` and `b`. `cells` must hold
+/// `(a.len() + 1) * (b.len() + 1)` values; an infinite cost forbids that
+/// operation, and the result is infinite if `b` cannot be reached.
+pub fn edit_distance<A, N>(a: &[A], b: &[A], costs: EditCosts<N>, cells: &mut [N]) -> N
+where
+    A: PartialEq,
+    N: Infinite + Relax,
+    N::Value: Zero,
+{
+    let zero = N::finite(N::Value::zero());
+    let mut table = DpTable::new(cells, a.len() + 1, b.len() + 1);
+    table[(0, 0)] = zero;
+
+    for i in 0..=a.len() {
+        for j in 0..=b.len() {
+            let current = table[(i, j)];
+            if current.is_infinite() {
+                continue;
+            }
+            if i < a.len() {
+                if let Some(cost) = current.try_add(costs.delete) {
+                    table[(i + 1, j)].min_assign(cost);
+                }
+            }
+            if j < b.len() {
+                if let Some(cost) = current.try_add(costs.insert) {
+                    table[(i, j + 1)].min_assign(cost);
+                }
+            }
+            if i < a.len() && j < b.len() {
+                let step = if a[i] == b[j] { zero } else { costs.substitute };
+                if let Some(cost) = current.try_add(step) {
+                    table[(i + 1, j + 1)].min_assign(cost);
+                }
+            }
+        }
+    }
+    table[(a.len(), b.len())]
+}
+
+/// 0/1 knapsack over `(weight, value)` items, returning the best total value
+/// within `capacity`. `cells[v]` ends up as the least weight reaching value
+/// exactly `v`, infinite if infeasible, so it must hold the sum of all values
+/// plus one.
+pub fn knapsack<N>(items: &[(N::Value, usize)], capacity: N::Value, cells: &mut [N]) -> usize
+where
+    N: Infinite + Relax,
+    N::Value: Zero,
+{
+    let total: usize = items.iter().map(|&(_, value)| value).sum();
+    if cells.len() != total + 1 {
+        panic!("slice length does not match the total value")
+    }
+    cells.fill(N::infinity());
+    cells[0] = N::finite(N::Value::zero());
+
+    for &(weight, value) in items {
+        for v in (value..=total).rev() {
+            if let Some(candidate) = cells[v - value].try_add(N::finite(weight)) {
+                cells[v].min_assign(candidate);
+            }
+        }
+    }
+    let capacity = N::finite(capacity);
+    cells.iter().rposition(|&weight| weight <= capacity).unwrap_or(0)
+}
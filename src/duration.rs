@@ -0,0 +1,150 @@
+//! Timeouts where infinity means "wait forever", as a replacement for
+//! `Option<Duration>`.
+//!
+//! Times are nanoseconds read from any monotonic tick source, counted from
+//! an arbitrary fixed point, so no OS clock is needed.
+
+use core::ops::{Add, Sub};
+use core::time::Duration;
+
+use crate::intfinity::SingleInfiniteNumber;
+
+/// A duration in nanoseconds that may be infinite. Like the numbers it wraps,
+/// `+` promotes overflow to infinity and `-` clamps at zero; `min` picks the
+/// shorter timeout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct InfDuration(SingleInfiniteNumber<u64>);
+
+/// The point in time a timeout expires, or never.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Deadline(SingleInfiniteNumber<u64>);
+
+impl InfDuration {
+    pub const ZERO: Self = InfDuration(SingleInfiniteNumber::Finite(0));
+    pub const INFINITE: Self = InfDuration(SingleInfiniteNumber::Infinity);
+
+    pub fn from_nanos(nanos: u64) -> Self {
+        InfDuration(SingleInfiniteNumber::Finite(nanos))
+    }
+
+    /// Infinite if the value overflows `u64` nanoseconds.
+    pub fn from_millis(millis: u64) -> Self {
+        InfDuration(SingleInfiniteNumber::Finite(millis) * SingleInfiniteNumber::Finite(1_000_000))
+    }
+
+    /// Infinite if the value overflows `u64` nanoseconds.
+    pub fn from_secs(secs: u64) -> Self {
+        InfDuration(SingleInfiniteNumber::Finite(secs) * SingleInfiniteNumber::Finite(1_000_000_000))
+    }
+
+    pub fn as_nanos(self) -> SingleInfiniteNumber<u64> {
+        self.0
+    }
+
+    pub fn is_infinite(self) -> bool {
+        self == Self::INFINITE
+    }
+
+    /// `None` when infinite.
+    pub fn to_duration(self) -> Option<Duration> {
+        match self.0 {
+            SingleInfiniteNumber::Finite(nanos) => Some(Duration::from_nanos(nanos)),
+            SingleInfiniteNumber::Infinity => None,
+        }
+    }
+
+    /// The deadline this timeout sets when started at `now`. A finite
+    /// timeout that overflows the tick counter never expires.
+    pub fn deadline(self, now: u64) -> Deadline {
+        Deadline(SingleInfiniteNumber::Finite(now) + self.0)
+    }
+
+    /// Like `deadline`, but `None` if a finite timeout overflows the tick
+    /// counter instead of becoming `Deadline::NEVER`.
+    pub fn checked_deadline(self, now: u64) -> Option<Deadline> {
+        SingleInfiniteNumber::Finite(now).checked_add(self.0).map(Deadline)
+    }
+}
+
+impl Deadline {
+    pub const NEVER: Self = Deadline(SingleInfiniteNumber::Infinity);
+
+    pub fn at(ticks: u64) -> Self {
+        Deadline(SingleInfiniteNumber::Finite(ticks))
+    }
+
+    pub fn ticks(self) -> SingleInfiniteNumber<u64> {
+        self.0
+    }
+
+    /// Zero once the deadline has passed, infinite if it never expires.
+    pub fn remaining(self, now: u64) -> InfDuration {
+        InfDuration(self.0 - SingleInfiniteNumber::Finite(now))
+    }
+
+    pub fn is_expired(self, now: u64) -> bool {
+        self.0 <= SingleInfiniteNumber::Finite(now)
+    }
+}
+
+/// Durations past `u64::MAX` nanoseconds (about 584 years) become infinite.
+impl From<Duration> for InfDuration {
+    fn from(duration: Duration) -> Self {
+        match u64::try_from(duration.as_nanos()) {
+            Ok(nanos) => InfDuration::from_nanos(nanos),
+            Err(_) => InfDuration::INFINITE,
+        }
+    }
+}
+
+/// `None` becomes infinite.
+impl From<Option<Duration>> for InfDuration {
+    fn from(duration: Option<Duration>) -> Self {
+        duration.map_or(InfDuration::INFINITE, InfDuration::from)
+    }
+}
+
+impl From<InfDuration> for Option<Duration> {
+    fn from(duration: InfDuration) -> Self {
+        duration.to_duration()
+    }
+}
+
+impl From<SingleInfiniteNumber<u64>> for InfDuration {
+    fn from(nanos: SingleInfiniteNumber<u64>) -> Self {
+        InfDuration(nanos)
+    }
+}
+
+impl Add for InfDuration {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        InfDuration(self.0 + other.0)
+    }
+}
+
+impl Sub for InfDuration {
+    type Output = Self;
+
+    /// `inf - inf` stays infinite, as for `SingleInfiniteNumber`.
+    fn sub(self, other: Self) -> Self {
+        InfDuration(self.0 - other.0)
+    }
+}
+
+impl Add<Duration> for InfDuration {
+    type Output = Self;
+
+    fn add(self, other: Duration) -> Self {
+        self + InfDuration::from(other)
+    }
+}
+
+impl Sub<Duration> for InfDuration {
+    type Output = Self;
+
+    fn sub(self, other: Duration) -> Self {
+        self - InfDuration::from(other)
+    }
+}
@@ -0,0 +1,391 @@
+use alloc::collections::BinaryHeap;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+use core::ops::{Add, Sub};
+
+use crate::intfinity::DoubleInfiniteNumber;
+use crate::matrix::SliceMatrix;
+use crate::semiring::Semiring;
+use crate::traits::{CheckedAdd, CheckedSub, Infinite, NegInfinite, Zero};
+use crate::tropical::MinPlus;
+
+/// A directed graph with vertices `0..vertex_count()` and weighted out-edges.
+/// Undirected graphs store every edge in both directions.
+pub trait Graph {
+    type Weight: Copy;
+
+    fn vertex_count(&self) -> usize;
+    fn neighbors(&self, vertex: usize) -> impl Iterator<Item = (usize, Self::Weight)> + '_;
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AdjacencyList<W> {
+    edges: Vec<Vec<(usize, W)>>,
+}
+
+/// Compressed sparse row graph: the out-edges of `u` are
+/// `targets[offsets[u]..offsets[u + 1]]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Csr<W> {
+    offsets: Vec<usize>,
+    targets: Vec<usize>,
+    weights: Vec<W>,
+}
+
+impl<W: Copy> AdjacencyList<W> {
+    pub fn new(vertices: usize) -> Self {
+        AdjacencyList { edges: vec![Vec::new(); vertices] }
+    }
+
+    pub fn add_edge(&mut self, from: usize, to: usize, weight: W) {
+        self.edges[from].push((to, weight));
+    }
+
+    pub fn add_undirected_edge(&mut self, u: usize, v: usize, weight: W) {
+        self.edges[u].push((v, weight));
+        self.edges[v].push((u, weight));
+    }
+}
+
+impl<W: Copy> Graph for AdjacencyList<W> {
+    type Weight = W;
+
+    fn vertex_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    fn neighbors(&self, vertex: usize) -> impl Iterator<Item = (usize, W)> + '_ {
+        self.edges[vertex].iter().copied()
+    }
+}
+
+impl<W: Copy> Csr<W> {
+    pub fn from_edges(vertices: usize, edges: &[(usize, usize, W)]) -> Self {
+        let mut offsets = vec![0; vertices + 1];
+        for &(from, _, _) in edges {
+            offsets[from + 1] += 1;
+        }
+        for i in 0..vertices {
+            offsets[i + 1] += offsets[i];
+        }
+        let mut slots: Vec<Option<(usize, W)>> = vec![None; edges.len()];
+        let mut next = offsets.clone();
+        for &(from, to, weight) in edges {
+            slots[next[from]] = Some((to, weight));
+            next[from] += 1;
+        }
+        let (targets, weights) = slots.into_iter().flatten().unzip();
+        Csr { offsets, targets, weights }
+    }
+}
+
+impl<W: Copy> Graph for Csr<W> {
+    type Weight = W;
+
+    fn vertex_count(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    fn neighbors(&self, vertex: usize) -> impl Iterator<Item = (usize, W)> + '_ {
+        let range = self.offsets[vertex]..self.offsets[vertex + 1];
+        self.targets[range.clone()].iter().copied().zip(self.weights[range].iter().copied())
+    }
+}
+
+/// Single-source shortest paths for non-negative weights. Unreachable
+/// vertices stay at infinity.
+pub fn dijkstra<G, N>(graph: &G, source: usize) -> Vec<N>
+where
+    G: Graph,
+    N: Infinite<Value = G::Weight>,
+    G::Weight: Zero,
+{
+    let mut dist = vec![N::infinity(); graph.vertex_count()];
+    dist[source] = N::finite(G::Weight::zero());
+    let mut heap = BinaryHeap::new();
+    heap.push(Reverse((dist[source], source)));
+
+    while let Some(Reverse((d, u))) = heap.pop() {
+        if d > dist[u] {
+            continue;
+        }
+        for (v, weight) in graph.neighbors(u) {
+            if let Some(candidate) = d.try_add(N::finite(weight)) {
+                if candidate < dist[v] {
+                    dist[v] = candidate;
+                    heap.push(Reverse((candidate, v)));
+                }
+            }
+        }
+    }
+    dist
+}
+
+/// Single-source shortest paths allowing negative weights. Every vertex
+/// reachable through a negative cycle gets `NegInfinity`.
+pub fn bellman_ford<G, N>(graph: &G, source: usize) -> Vec<N>
+where
+    G: Graph,
+    N: NegInfinite<Value = G::Weight>,
+    G::Weight: Zero,
+{
+    let n = graph.vertex_count();
+    let mut dist = vec![N::infinity(); n];
+    dist[source] = N::finite(G::Weight::zero());
+
+    for _ in 1..n {
+        let mut changed = false;
+        for u in 0..n {
+            if dist[u] == N::infinity() {
+                continue;
+            }
+            for (v, weight) in graph.neighbors(u) {
+                if let Some(candidate) = dist[u].try_add(N::finite(weight)) {
+                    if candidate < dist[v] {
+                        dist[v] = candidate;
+                        changed = true;
+                    }
+                }
+            }
+        }
+        if !changed {
+            return dist;
+        }
+    }
+
+    // whatever can still be relaxed lies on or behind a negative cycle
+    let mut stack = Vec::new();
+    for u in 0..n {
+        if dist[u] == N::infinity() {
+            continue;
+        }
+        for (v, weight) in graph.neighbors(u) {
+            if let Some(candidate) = dist[u].try_add(N::finite(weight)) {
+                if candidate < dist[v] && dist[v] != N::neg_infinity() {
+                    dist[v] = N::neg_infinity();
+                    stack.push(v);
+                }
+            }
+        }
+    }
+    while let Some(u) = stack.pop() {
+        for (v, _) in graph.neighbors(u) {
+            if dist[v] != N::neg_infinity() {
+                dist[v] = N::neg_infinity();
+                stack.push(v);
+            }
+        }
+    }
+    dist
+}
+
+/// All-pairs shortest paths, computed as the Kleene star of the weight
+/// matrix over `MinPlus`. Pairs connected through a negative cycle get
+/// `NegInfinity` when `N` has one.
+pub fn floyd_warshall<G, N>(graph: &G) -> Vec<Vec<N>>
+where
+    G: Graph,
+    N: Infinite<Value = G::Weight>,
+    MinPlus<N>: Semiring,
+{
+    let n = graph.vertex_count();
+    let mut data = vec![<MinPlus<N> as Semiring>::zero(); n * n];
+    let mut matrix = SliceMatrix::new(&mut data, n, n);
+    for u in 0..n {
+        for (v, weight) in graph.neighbors(u) {
+            matrix[(u, v)] = matrix[(u, v)].plus(MinPlus(N::finite(weight)));
+        }
+    }
+    if !matrix.star_in_place() {
+        panic!("min-plus star is undefined")
+    }
+    data.chunks(n.max(1)).take(n).map(|row| row.iter().map(|d| d.0).collect()).collect()
+}
+
+struct Augmented<'a, G> {
+    graph: &'a G,
+}
+
+impl<G> Graph for Augmented<'_, G>
+where
+    G: Graph,
+    G::Weight: Zero,
+{
+    type Weight = G::Weight;
+
+    fn vertex_count(&self) -> usize {
+        self.graph.vertex_count() + 1
+    }
+
+    // the extra last vertex has a zero-weight edge to every other vertex
+    fn neighbors(&self, vertex: usize) -> impl Iterator<Item = (usize, G::Weight)> + '_ {
+        let n = self.graph.vertex_count();
+        let (inner, extra) = if vertex < n { (Some(self.graph.neighbors(vertex)), 0) } else { (None, n) };
+        inner.into_iter().flatten().chain((0..extra).map(|v| (v, G::Weight::zero())))
+    }
+}
+
+struct Reweighted<'a, G, T> {
+    graph: &'a G,
+    potential: &'a [T],
+}
+
+// `x + add - sub`, trying each order of the two steps so that an intermediate
+// overflow does not hide a result that fits
+fn add_sub<T: Copy + CheckedAdd + CheckedSub>(x: T, add: T, sub: T) -> Option<T> {
+    x.checked_add(add).and_then(|y| y.checked_sub(sub))
+        .or_else(|| x.checked_sub(sub).and_then(|y| y.checked_add(add)))
+        .or_else(|| add.checked_sub(sub).and_then(|d| x.checked_add(d)))
+}
+
+impl<G, T> Graph for Reweighted<'_, G, T>
+where
+    G: Graph<Weight = T>,
+    T: Copy + Ord + Add<Output = T> + Sub<Output = T> + Zero + CheckedAdd + CheckedSub,
+{
+    type Weight = T;
+
+    fn vertex_count(&self) -> usize {
+        self.graph.vertex_count()
+    }
+
+    fn neighbors(&self, vertex: usize) -> impl Iterator<Item = (usize, T)> + '_ {
+        let h = self.potential;
+        self.graph.neighbors(vertex).map(move |(v, weight)| match add_sub(weight, h[vertex], h[v]) {
+            Some(w) => (v, w),
+            None => panic!("reweighted edge weight overflows"),
+        })
+    }
+}
+
+/// All-pairs shortest paths for sparse graphs with negative weights, or
+/// `None` if the graph has a negative cycle. Assumes reweighted edges
+/// `w(u, v) + h(u) - h(v)` fit into `T`.
+pub fn johnson<G, T>(graph: &G) -> Option<Vec<Vec<DoubleInfiniteNumber<T>>>>
+where
+    G: Graph<Weight = T>,
+    T: Copy + Ord + Add<Output = T> + Sub<Output = T> + Zero + CheckedAdd + CheckedSub,
+{
+    let n = graph.vertex_count();
+    let mut potential: Vec<DoubleInfiniteNumber<T>> = bellman_ford(&Augmented { graph }, n);
+    potential.truncate(n);
+    // the extra vertex reaches every vertex, so only a negative cycle leaves
+    // a potential that is not finite
+    let potential: Vec<T> = potential
+        .into_iter()
+        .map(|h| match h {
+            DoubleInfiniteNumber::Finite(h) => Some(h),
+            _ => None,
+        })
+        .collect::<Option<_>>()?;
+
+    let reweighted = Reweighted { graph, potential: &potential };
+    let all = (0..n)
+        .map(|u| {
+            let dist: Vec<DoubleInfiniteNumber<T>> = dijkstra(&reweighted, u);
+            dist.into_iter()
+                .enumerate()
+                .map(|(v, d)| match d {
+                    DoubleInfiniteNumber::Finite(d) => match add_sub(d, potential[v], potential[u]) {
+                        Some(exact) => DoubleInfiniteNumber::Finite(exact),
+                        None => DoubleInfiniteNumber::Finite(d) - DoubleInfiniteNumber::Finite(potential[u]) + DoubleInfiniteNumber::Finite(potential[v]),
+                    },
+                    _ => d,
+                })
+                .collect()
+        })
+        .collect();
+    Some(all)
+}
+
+/// Prim's minimum spanning forest over an undirected graph. Returns the
+/// parent of every vertex (`None` for tree roots) and the weight of the edge
+/// connecting it, with zero for roots.
+pub fn prim<G, N>(graph: &G) -> (Vec<Option<usize>>, Vec<N>)
+where
+    G: Graph,
+    N: Infinite<Value = G::Weight>,
+    G::Weight: Zero,
+{
+    let n = graph.vertex_count();
+    let mut key = vec![N::infinity(); n];
+    let mut parent = vec![None; n];
+    let mut in_tree = vec![false; n];
+    let mut heap = BinaryHeap::new();
+
+    for root in 0..n {
+        if in_tree[root] {
+            continue;
+        }
+        key[root] = N::finite(G::Weight::zero());
+        heap.push(Reverse((key[root], root)));
+        while let Some(Reverse((_, u))) = heap.pop() {
+            if in_tree[u] {
+                continue;
+            }
+            in_tree[u] = true;
+            for (v, weight) in graph.neighbors(u) {
+                let weight = N::finite(weight);
+                if !in_tree[v] && weight < key[v] {
+                    key[v] = weight;
+                    parent[v] = Some(u);
+                    heap.push(Reverse((weight, v)));
+                }
+            }
+        }
+    }
+    (parent, key)
+}
+
+struct DisjointSets {
+    parent: Vec<usize>,
+    rank: Vec<u8>,
+}
+
+impl DisjointSets {
+    fn new(n: usize) -> Self {
+        DisjointSets { parent: (0..n).collect(), rank: vec![0; n] }
+    }
+
+    fn find(&mut self, mut x: usize) -> usize {
+        while self.parent[x] != x {
+            self.parent[x] = self.parent[self.parent[x]];
+            x = self.parent[x];
+        }
+        x
+    }
+
+    fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        match self.rank[a].cmp(&self.rank[b]) {
+            core::cmp::Ordering::Less => self.parent[a] = b,
+            core::cmp::Ordering::Greater => self.parent[b] = a,
+            core::cmp::Ordering::Equal => {
+                self.parent[b] = a;
+                self.rank[a] += 1;
+            }
+        }
+        true
+    }
+}
+
+/// Kruskal's minimum spanning forest over an undirected graph, as a list of
+/// `(u, v, weight)` edges in the order they were taken.
+pub fn kruskal<G>(graph: &G) -> Vec<(usize, usize, G::Weight)>
+where
+    G: Graph,
+    G::Weight: Ord,
+{
+    let n = graph.vertex_count();
+    let mut edges: Vec<(usize, usize, G::Weight)> = (0..n)
+        .flat_map(|u| graph.neighbors(u).filter(move |&(v, _)| u <= v).map(move |(v, w)| (u, v, w)))
+        .collect();
+    edges.sort_by_key(|&(_, _, weight)| weight);
+
+    let mut sets = DisjointSets::new(n);
+    edges.into_iter().filter(|&(u, v, _)| sets.union(u, v)).collect()
+}
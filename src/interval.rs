@@ -0,0 +1,344 @@
+use core::ops::{Add, Sub, Mul, Div};
+
+use crate::intfinity::DoubleInfiniteNumber;
+use crate::traits::{Bounded, CheckedAdd, CheckedSub, CheckedMul, CheckedDiv, Zero};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoundType {
+    Open,
+    Closed,
+}
+
+/// The `T` values between `lo` and `hi`, where each side is open or closed
+/// and either endpoint can be infinite. Infinite endpoints are always open
+/// and every empty interval compares equal to `Interval::empty()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Interval<T> {
+    lo: DoubleInfiniteNumber<T>,
+    hi: DoubleInfiniteNumber<T>,
+    lo_type: BoundType,
+    hi_type: BoundType,
+}
+
+#[derive(Clone, Copy)]
+struct Endpoint<T> {
+    value: DoubleInfiniteNumber<T>,
+    closed: bool,
+}
+
+fn bound_type(closed: bool) -> BoundType {
+    if closed { BoundType::Closed } else { BoundType::Open }
+}
+
+impl<T> Interval<T>
+where
+    T: Copy + Ord,
+{
+    pub fn new(lo: DoubleInfiniteNumber<T>, lo_type: BoundType, hi: DoubleInfiniteNumber<T>, hi_type: BoundType) -> Self {
+        let lo_type = if matches!(lo, DoubleInfiniteNumber::Finite(_)) { lo_type } else { BoundType::Open };
+        let hi_type = if matches!(hi, DoubleInfiniteNumber::Finite(_)) { hi_type } else { BoundType::Open };
+        let empty = lo > hi || (lo == hi && (lo_type == BoundType::Open || hi_type == BoundType::Open));
+        if empty {
+            Self::empty()
+        } else {
+            Interval { lo, hi, lo_type, hi_type }
+        }
+    }
+
+    pub fn closed(lo: DoubleInfiniteNumber<T>, hi: DoubleInfiniteNumber<T>) -> Self {
+        Self::new(lo, BoundType::Closed, hi, BoundType::Closed)
+    }
+
+    pub fn open(lo: DoubleInfiniteNumber<T>, hi: DoubleInfiniteNumber<T>) -> Self {
+        Self::new(lo, BoundType::Open, hi, BoundType::Open)
+    }
+
+    pub fn point(value: T) -> Self {
+        Self::closed(DoubleInfiniteNumber::Finite(value), DoubleInfiniteNumber::Finite(value))
+    }
+
+    pub fn empty() -> Self {
+        Interval {
+            lo: DoubleInfiniteNumber::PosInfinity,
+            hi: DoubleInfiniteNumber::NegInfinity,
+            lo_type: BoundType::Open,
+            hi_type: BoundType::Open,
+        }
+    }
+
+    pub fn entire() -> Self {
+        Interval {
+            lo: DoubleInfiniteNumber::NegInfinity,
+            hi: DoubleInfiniteNumber::PosInfinity,
+            lo_type: BoundType::Open,
+            hi_type: BoundType::Open,
+        }
+    }
+
+    pub fn lo(&self) -> DoubleInfiniteNumber<T> {
+        self.lo
+    }
+
+    pub fn hi(&self) -> DoubleInfiniteNumber<T> {
+        self.hi
+    }
+
+    pub fn lo_type(&self) -> BoundType {
+        self.lo_type
+    }
+
+    pub fn hi_type(&self) -> BoundType {
+        self.hi_type
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lo > self.hi
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let value = DoubleInfiniteNumber::Finite(value);
+        let above_lo = match self.lo_type {
+            BoundType::Closed => value >= self.lo,
+            BoundType::Open => value > self.lo,
+        };
+        let below_hi = match self.hi_type {
+            BoundType::Closed => value <= self.hi,
+            BoundType::Open => value < self.hi,
+        };
+        above_lo && below_hi
+    }
+
+    pub fn intersect(&self, other: &Self) -> Self {
+        let (lo, lo_type) = match self.lo.cmp(&other.lo) {
+            core::cmp::Ordering::Greater => (self.lo, self.lo_type),
+            core::cmp::Ordering::Less => (other.lo, other.lo_type),
+            core::cmp::Ordering::Equal => (self.lo, Self::narrower(self.lo_type, other.lo_type)),
+        };
+        let (hi, hi_type) = match self.hi.cmp(&other.hi) {
+            core::cmp::Ordering::Less => (self.hi, self.hi_type),
+            core::cmp::Ordering::Greater => (other.hi, other.hi_type),
+            core::cmp::Ordering::Equal => (self.hi, Self::narrower(self.hi_type, other.hi_type)),
+        };
+        Self::new(lo, lo_type, hi, hi_type)
+    }
+
+    /// Smallest interval containing both `self` and `other`.
+    pub fn hull(&self, other: &Self) -> Self {
+        if self.is_empty() {
+            return *other;
+        }
+        if other.is_empty() {
+            return *self;
+        }
+        let (lo, lo_type) = match self.lo.cmp(&other.lo) {
+            core::cmp::Ordering::Less => (self.lo, self.lo_type),
+            core::cmp::Ordering::Greater => (other.lo, other.lo_type),
+            core::cmp::Ordering::Equal => (self.lo, Self::wider(self.lo_type, other.lo_type)),
+        };
+        let (hi, hi_type) = match self.hi.cmp(&other.hi) {
+            core::cmp::Ordering::Greater => (self.hi, self.hi_type),
+            core::cmp::Ordering::Less => (other.hi, other.hi_type),
+            core::cmp::Ordering::Equal => (self.hi, Self::wider(self.hi_type, other.hi_type)),
+        };
+        Self::new(lo, lo_type, hi, hi_type)
+    }
+
+    fn narrower(a: BoundType, b: BoundType) -> BoundType {
+        if a == BoundType::Closed && b == BoundType::Closed { BoundType::Closed } else { BoundType::Open }
+    }
+
+    fn wider(a: BoundType, b: BoundType) -> BoundType {
+        if a == BoundType::Closed || b == BoundType::Closed { BoundType::Closed } else { BoundType::Open }
+    }
+
+    fn endpoints(&self) -> (Endpoint<T>, Endpoint<T>) {
+        (
+            Endpoint { value: self.lo, closed: self.lo_type == BoundType::Closed },
+            Endpoint { value: self.hi, closed: self.hi_type == BoundType::Closed },
+        )
+    }
+
+    // lowest and highest of the candidate endpoints, closed ones winning ties
+    fn extremes(candidates: &[Endpoint<T>]) -> (Endpoint<T>, Endpoint<T>) {
+        let mut lo = candidates[0];
+        let mut hi = candidates[0];
+        for &c in &candidates[1..] {
+            if c.value < lo.value || (c.value == lo.value && c.closed) {
+                lo = c;
+            }
+            if c.value > hi.value || (c.value == hi.value && c.closed) {
+                hi = c;
+            }
+        }
+        (lo, hi)
+    }
+}
+
+impl<T> Interval<T>
+where
+    T: Copy + Ord + Bounded,
+{
+    // A non-empty operand never has a `+inf` lower or `-inf` upper bound, so
+    // a result endpoint on that side can only come from finite values
+    // overflowing. The exact bound lies past `T`'s range; the half-line
+    // beyond `MAX` or `MIN` still encloses the result.
+    fn from_overflowing(lo: DoubleInfiniteNumber<T>, lo_type: BoundType, hi: DoubleInfiniteNumber<T>, hi_type: BoundType) -> Self {
+        let (lo, lo_type) = match lo {
+            DoubleInfiniteNumber::PosInfinity => (DoubleInfiniteNumber::Finite(T::MAX_VALUE), BoundType::Open),
+            _ => (lo, lo_type),
+        };
+        let (hi, hi_type) = match hi {
+            DoubleInfiniteNumber::NegInfinity => (DoubleInfiniteNumber::Finite(T::MIN_VALUE), BoundType::Open),
+            _ => (hi, hi_type),
+        };
+        Self::new(lo, lo_type, hi, hi_type)
+    }
+}
+
+impl<T> Interval<T>
+where
+    T: Copy + Ord + Zero + Sub<Output = T> + CheckedSub,
+{
+    /// `hi - lo`, infinite for unbounded intervals and zero for empty ones.
+    pub fn width(&self) -> DoubleInfiniteNumber<T> {
+        if self.is_empty() {
+            DoubleInfiniteNumber::Finite(T::zero())
+        } else {
+            self.hi - self.lo
+        }
+    }
+}
+
+impl<T> Add for Interval<T>
+where
+    T: Copy + Ord + Bounded + Zero + Add<Output = T> + CheckedAdd,
+{
+    type Output = Self;
+
+    // lo + lo and hi + hi never meet opposite infinities in non-empty intervals
+    fn add(self, other: Self) -> Self::Output {
+        if self.is_empty() || other.is_empty() {
+            return Self::empty();
+        }
+        Self::from_overflowing(
+            self.lo + other.lo,
+            Self::narrower(self.lo_type, other.lo_type),
+            self.hi + other.hi,
+            Self::narrower(self.hi_type, other.hi_type),
+        )
+    }
+}
+
+impl<T> Sub for Interval<T>
+where
+    T: Copy + Ord + Bounded + Zero + Sub<Output = T> + CheckedSub,
+{
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        if self.is_empty() || other.is_empty() {
+            return Self::empty();
+        }
+        Self::from_overflowing(
+            self.lo - other.hi,
+            Self::narrower(self.lo_type, other.hi_type),
+            self.hi - other.lo,
+            Self::narrower(self.hi_type, other.lo_type),
+        )
+    }
+}
+
+impl<T> Mul for Interval<T>
+where
+    T: Copy + Ord + Bounded + Zero + Mul<Output = T> + CheckedMul,
+{
+    type Output = Self;
+
+    // 0 * inf counts as 0 here: an infinite endpoint is never attained, so
+    // the products only approach it from finite values
+    fn mul(self, other: Self) -> Self::Output {
+        if self.is_empty() || other.is_empty() {
+            return Self::empty();
+        }
+        let product = |a: Endpoint<T>, b: Endpoint<T>| {
+            if a.value.is_zero() || b.value.is_zero() {
+                let zero_closed = (a.value.is_zero() && a.closed) || (b.value.is_zero() && b.closed);
+                Endpoint { value: DoubleInfiniteNumber::Finite(T::zero()), closed: zero_closed || (a.closed && b.closed) }
+            } else {
+                Endpoint { value: a.value * b.value, closed: a.closed && b.closed }
+            }
+        };
+        let (a, b) = self.endpoints();
+        let (c, d) = other.endpoints();
+        let (lo, hi) = Self::extremes(&[product(a, c), product(a, d), product(b, c), product(b, d)]);
+        Self::from_overflowing(lo.value, bound_type(lo.closed), hi.value, bound_type(hi.closed))
+    }
+}
+
+impl<T> Div for Interval<T>
+where
+    T: Copy + Ord + Bounded + Zero + Div<Output = T> + CheckedDiv,
+{
+    type Output = Self;
+
+    /// Integer division truncates, so finite endpoints of a quotient are
+    /// reported closed, which always encloses the exact result. A divisor
+    /// touching zero follows the usual extended rules: `x / [0, d]` is
+    /// `[a / d, +inf)` for non-negative `x`, an interval straddling zero
+    /// yields the entire line, and `x / [0, 0]` is empty.
+    fn div(self, other: Self) -> Self::Output {
+        if self.is_empty() || other.is_empty() {
+            return Self::empty();
+        }
+        let zero = DoubleInfiniteNumber::Finite(T::zero());
+        let closed = |value: DoubleInfiniteNumber<T>| Endpoint { value, closed: true };
+        let (a, b) = (self.lo, self.hi);
+        let (c, d) = (other.lo, other.hi);
+
+        if c <= zero && zero <= d {
+            if c == zero && d == zero {
+                return Self::empty();
+            }
+            if a == zero && b == zero {
+                return Self::point(T::zero());
+            }
+            let pos_inf = DoubleInfiniteNumber::PosInfinity;
+            let neg_inf = DoubleInfiniteNumber::NegInfinity;
+            return if c < zero && zero < d {
+                Self::entire()
+            } else if c == zero {
+                // divisor is (0, d]
+                if a >= zero {
+                    Self::new(a / d, BoundType::Closed, pos_inf, BoundType::Open)
+                } else if b <= zero {
+                    Self::new(neg_inf, BoundType::Open, b / d, BoundType::Closed)
+                } else {
+                    Self::entire()
+                }
+            } else {
+                // divisor is [c, 0)
+                if a >= zero {
+                    Self::new(neg_inf, BoundType::Open, a / c, BoundType::Closed)
+                } else if b <= zero {
+                    Self::new(b / c, BoundType::Closed, pos_inf, BoundType::Open)
+                } else {
+                    Self::entire()
+                }
+            };
+        }
+
+        // inf / inf corners are skipped, the remaining corners already span
+        // everything such a quotient could approach
+        let mut candidates = [closed(zero); 4];
+        let mut count = 0;
+        for (x, y) in [(a, c), (a, d), (b, c), (b, d)] {
+            let both_infinite = !matches!(x, DoubleInfiniteNumber::Finite(_)) && !matches!(y, DoubleInfiniteNumber::Finite(_));
+            if !both_infinite {
+                candidates[count] = closed(x / y);
+                count += 1;
+            }
+        }
+        let (lo, hi) = Self::extremes(&candidates[..count]);
+        Self::from_overflowing(lo.value, bound_type(lo.closed), hi.value, bound_type(hi.closed))
+    }
+}
@@ -1,4 +1,6 @@
-use crate::traits::{Zero, Negate, Unsigned};
+use core::ops::Add;
+
+use crate::traits::{Zero, CheckedNeg, Unsigned, CheckedAdd, Infinite, NegInfinite};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum DoubleInfiniteNumber<T> {
@@ -30,17 +32,27 @@ where
 
 impl<T> DoubleInfiniteNumber<T>
 where
-    T: Copy + Negate,
+    T: Copy + CheckedNeg,
 {
+    /// `-MIN` is not representable and is promoted to `PosInfinity`.
     pub fn negate_double_bounded_infinity(self) -> Self {
         match self {
-            DoubleInfiniteNumber::Finite(value) => DoubleInfiniteNumber::Finite(value.negate()), 
+            DoubleInfiniteNumber::Finite(value) => value.checked_neg().map_or(DoubleInfiniteNumber::PosInfinity, DoubleInfiniteNumber::Finite),
             DoubleInfiniteNumber::PosInfinity => DoubleInfiniteNumber::NegInfinity,  
             DoubleInfiniteNumber::NegInfinity => DoubleInfiniteNumber::PosInfinity,  
         }
     }
 }
 
+impl<T> Default for DoubleInfiniteNumber<T>
+where
+    T: Zero,
+{
+    fn default() -> Self {
+        DoubleInfiniteNumber::Finite(T::zero())
+    }
+}
+
 impl<T> core::fmt::Display for DoubleInfiniteNumber<T>
 where
     T: core::fmt::Display,
@@ -54,6 +66,44 @@ where
     }
 }
 
+impl<T> Infinite for DoubleInfiniteNumber<T>
+where
+    T: Copy + Ord + Add<Output = T> + Zero + CheckedAdd,
+{
+    type Value = T;
+
+    fn infinity() -> Self {
+        DoubleInfiniteNumber::PosInfinity
+    }
+
+    fn finite(value: T) -> Self {
+        DoubleInfiniteNumber::Finite(value)
+    }
+
+    fn as_finite(&self) -> Option<T> {
+        match self {
+            DoubleInfiniteNumber::Finite(val) => Some(*val),
+            _ => None,
+        }
+    }
+
+    fn try_add(self, other: Self) -> Option<Self> {
+        match (self, other) {
+            (DoubleInfiniteNumber::PosInfinity, DoubleInfiniteNumber::NegInfinity) | (DoubleInfiniteNumber::NegInfinity, DoubleInfiniteNumber::PosInfinity) => None,
+            _ => Some(self + other),
+        }
+    }
+}
+
+impl<T> NegInfinite for DoubleInfiniteNumber<T>
+where
+    T: Copy + Ord + Add<Output = T> + Zero + CheckedAdd,
+{
+    fn neg_infinity() -> Self {
+        DoubleInfiniteNumber::NegInfinity
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum SingleInfiniteNumber<T: Unsigned> {
     Finite(T),
@@ -81,6 +131,15 @@ where
     }
 }
 
+impl<T> Default for SingleInfiniteNumber<T>
+where
+    T: Zero + Unsigned,
+{
+    fn default() -> Self {
+        SingleInfiniteNumber::Finite(T::zero())
+    }
+}
+
 impl<T> core::fmt::Display for SingleInfiniteNumber<T>
 where
     T: core::fmt::Display + Unsigned,
@@ -92,3 +151,29 @@ where
         }
     }
 }
+
+impl<T> Infinite for SingleInfiniteNumber<T>
+where
+    T: Copy + Ord + Add<Output = T> + Zero + CheckedAdd + Unsigned,
+{
+    type Value = T;
+
+    fn infinity() -> Self {
+        SingleInfiniteNumber::Infinity
+    }
+
+    fn finite(value: T) -> Self {
+        SingleInfiniteNumber::Finite(value)
+    }
+
+    fn as_finite(&self) -> Option<T> {
+        match self {
+            SingleInfiniteNumber::Finite(val) => Some(*val),
+            SingleInfiniteNumber::Infinity => None,
+        }
+    }
+
+    fn try_add(self, other: Self) -> Option<Self> {
+        Some(self + other)
+    }
+}
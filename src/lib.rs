@@ -1,36 +1,145 @@
 //hi
 #![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 pub mod traits;
 pub mod intfinity;
 pub mod operations;
 pub mod numeric_impls;
+pub mod tropical;
+pub mod semiring;
+pub mod matrix;
+pub mod interval;
+pub mod range;
+pub mod dp;
+pub mod ordering;
+pub mod arithmetic;
+pub mod traced;
+mod wide;
+pub mod bytes;
+pub mod codec;
+pub mod postgres_text;
+pub mod duration;
+#[cfg(feature = "graph")]
+pub mod graph;
+#[cfg(feature = "petgraph")]
+pub mod petgraph_impls;
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impls;
+#[cfg(feature = "rand")]
+pub mod rand_impls;
+#[cfg(feature = "serde")]
+mod serde_impls;
+#[cfg(feature = "schemars")]
+mod schemars_impls;
 
 pub use intfinity::{DoubleInfiniteNumber, SingleInfiniteNumber};
+#[cfg(feature = "derive")]
+pub use intfinity_derive::InfinityNumeric;
 
+/// Builds infinite numbers. Without a bound the result is a `DoubleInfiniteNumber`.
+///
+/// ```
+/// use intfinity::{intfinity, DoubleInfiniteNumber, SingleInfiniteNumber};
+///
+/// assert_eq!(intfinity!(5: i64), DoubleInfiniteNumber::<i64>::new(5));
+/// assert_eq!(intfinity!(-inf: i8), DoubleInfiniteNumber::<i8>::NegInfinity);
+/// assert_eq!(intfinity!(inf: u32, single_bound), SingleInfiniteNumber::<u32>::Infinity);
+///
+/// let costs: [DoubleInfiniteNumber<i32>; 3] = intfinity![1, inf, 3];
+/// assert_eq!(costs[1], DoubleInfiniteNumber::PosInfinity);
+/// ```
+///
+/// A single-bounded number has no negative infinity:
+///
+/// ```compile_fail
+/// let x: intfinity::SingleInfiniteNumber<u32> = intfinity::intfinity!(-inf, single_bound);
+/// ```
 #[macro_export]
 macro_rules! intfinity {
     (inf) => {
-        DoubleInfiniteNumber::PosInfinity
+        $crate::DoubleInfiniteNumber::PosInfinity
     };
     (-inf) => {
-        DoubleInfiniteNumber::NegInfinity
-    };
-    ($val:expr) => {
-        DoubleInfiniteNumber::new($val)
+        $crate::DoubleInfiniteNumber::NegInfinity
     };
     (inf, single_bound) => {
-        SingleInfiniteNumber::Infinity
+        $crate::SingleInfiniteNumber::Infinity
     };
-    ($val:expr, single_bound) => {
-        SingleInfiniteNumber::new($val)
+    (-inf, single_bound) => {
+        compile_error!("intfinity!: a single-bounded number has no -inf; use double_bound")
     };
     (inf, double_bound) => {
-        DoubleInfiniteNumber::PosInfinity
+        $crate::DoubleInfiniteNumber::PosInfinity
     };
     (-inf, double_bound) => {
-        DoubleInfiniteNumber::NegInfinity
+        $crate::DoubleInfiniteNumber::NegInfinity
+    };
+
+    // typed forms
+    (inf : $t:ty) => {
+        $crate::DoubleInfiniteNumber::<$t>::PosInfinity
+    };
+    (-inf : $t:ty) => {
+        $crate::DoubleInfiniteNumber::<$t>::NegInfinity
+    };
+    (inf : $t:ty, single_bound) => {
+        $crate::SingleInfiniteNumber::<$t>::Infinity
+    };
+    (-inf : $t:ty, single_bound) => {
+        compile_error!("intfinity!: a single-bounded number has no -inf; use double_bound")
+    };
+    (inf : $t:ty, double_bound) => {
+        $crate::DoubleInfiniteNumber::<$t>::PosInfinity
+    };
+    (-inf : $t:ty, double_bound) => {
+        $crate::DoubleInfiniteNumber::<$t>::NegInfinity
+    };
+    ($val:literal : $t:ty) => {
+        $crate::DoubleInfiniteNumber::<$t>::new($val)
+    };
+    ($val:ident : $t:ty) => {
+        $crate::DoubleInfiniteNumber::<$t>::new($val)
+    };
+    ($val:literal : $t:ty, single_bound) => {
+        $crate::SingleInfiniteNumber::<$t>::new($val)
+    };
+    ($val:ident : $t:ty, single_bound) => {
+        $crate::SingleInfiniteNumber::<$t>::new($val)
+    };
+    ($val:literal : $t:ty, double_bound) => {
+        $crate::DoubleInfiniteNumber::<$t>::new($val)
+    };
+    ($val:ident : $t:ty, double_bound) => {
+        $crate::DoubleInfiniteNumber::<$t>::new($val)
+    };
+
+    ($val:expr) => {
+        $crate::DoubleInfiniteNumber::new($val)
+    };
+    ($val:expr, single_bound) => {
+        $crate::SingleInfiniteNumber::new($val)
     };
     ($val:expr, double_bound) => {
-        DoubleInfiniteNumber::new($val)
+        $crate::DoubleInfiniteNumber::new($val)
+    };
+
+    // list form, munched one element at a time so `inf` stays recognisable
+    (@list [$($out:expr),*]) => {
+        [$($out),*]
+    };
+    (@list [$($out:expr),*] inf $(, $($rest:tt)*)?) => {
+        $crate::intfinity!(@list [$($out,)* $crate::DoubleInfiniteNumber::PosInfinity] $($($rest)*)?)
+    };
+    (@list [$($out:expr),*] -inf $(, $($rest:tt)*)?) => {
+        $crate::intfinity!(@list [$($out,)* $crate::DoubleInfiniteNumber::NegInfinity] $($($rest)*)?)
+    };
+    (@list [$($out:expr),*] $val:expr $(, $($rest:tt)*)?) => {
+        $crate::intfinity!(@list [$($out,)* $crate::DoubleInfiniteNumber::new($val)] $($($rest)*)?)
+    };
+    ($($items:tt)+) => {
+        $crate::intfinity!(@list [] $($items)+)
     };
-}
\ No newline at end of file
+}
@@ -0,0 +1,263 @@
+use core::ops::{Add, Index, IndexMut, Mul};
+
+use crate::semiring::Semiring;
+
+/// Fixed-size `R x C` matrix over a semiring, stored inline.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Matrix<S, const R: usize, const C: usize> {
+    rows: [[S; C]; R],
+}
+
+/// `rows x cols` matrix over a semiring, backed by a caller-provided
+/// row-major slice so that it works without an allocator.
+#[derive(Debug, PartialEq)]
+pub struct SliceMatrix<'a, S> {
+    data: &'a mut [S],
+    rows: usize,
+    cols: usize,
+}
+
+// out = a * b, with a: r x c, b: c x k, out: r x k
+fn mul_raw<S: Semiring>(a: &[S], b: &[S], out: &mut [S], r: usize, c: usize, k: usize) {
+    for i in 0..r {
+        for j in 0..k {
+            let mut acc = S::zero();
+            for m in 0..c {
+                acc = acc.plus(a[i * c + m].times(b[m * k + j]));
+            }
+            out[i * k + j] = acc;
+        }
+    }
+}
+
+fn identity_raw<S: Semiring>(a: &mut [S], n: usize) {
+    for i in 0..n {
+        for j in 0..n {
+            a[i * n + j] = if i == j { S::one() } else { S::zero() };
+        }
+    }
+}
+
+// in-place Floyd-Warshall-Kleene elimination, exact for idempotent semirings
+fn star_raw<S: Semiring>(a: &mut [S], n: usize) -> bool {
+    for k in 0..n {
+        let s = match a[k * n + k].star() {
+            Some(s) => s,
+            None => return false,
+        };
+        for i in 0..n {
+            let left = a[i * n + k].times(s);
+            for j in 0..n {
+                a[i * n + j] = a[i * n + j].plus(left.times(a[k * n + j]));
+            }
+        }
+    }
+    for i in 0..n {
+        a[i * n + i] = a[i * n + i].plus(S::one());
+    }
+    true
+}
+
+impl<S, const R: usize, const C: usize> Matrix<S, R, C>
+where
+    S: Semiring,
+{
+    pub fn new(rows: [[S; C]; R]) -> Self {
+        Matrix { rows }
+    }
+
+    pub fn zero() -> Self {
+        Matrix { rows: [[S::zero(); C]; R] }
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> S {
+        self.rows[row][col]
+    }
+
+    pub fn rows(&self) -> &[[S; C]; R] {
+        &self.rows
+    }
+}
+
+impl<S, const N: usize> Matrix<S, N, N>
+where
+    S: Semiring,
+{
+    pub fn identity() -> Self {
+        let mut m = Self::zero();
+        identity_raw(m.rows.as_flattened_mut(), N);
+        m
+    }
+
+    /// `self` multiplied with itself `exp` times, by repeated squaring.
+    pub fn pow(self, mut exp: u32) -> Self {
+        let mut base = self;
+        let mut acc = Self::identity();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                acc = acc * base;
+            }
+            base = base * base;
+            exp >>= 1;
+        }
+        acc
+    }
+
+    /// Kleene star `I + A + A^2 + ...`, e.g. all-pairs shortest paths over
+    /// `MinPlus`. Assumes idempotent addition, which holds for every semiring
+    /// in this crate. `None` if an element on the way has no star.
+    pub fn star(self) -> Option<Self> {
+        let mut m = self;
+        if star_raw(m.rows.as_flattened_mut(), N) {
+            Some(m)
+        } else {
+            None
+        }
+    }
+}
+
+impl<S, const R: usize, const C: usize, const K: usize> Mul<Matrix<S, C, K>> for Matrix<S, R, C>
+where
+    S: Semiring,
+{
+    type Output = Matrix<S, R, K>;
+
+    fn mul(self, other: Matrix<S, C, K>) -> Self::Output {
+        let mut out = Matrix::zero();
+        mul_raw(self.rows.as_flattened(), other.rows.as_flattened(), out.rows.as_flattened_mut(), R, C, K);
+        out
+    }
+}
+
+impl<S, const R: usize, const C: usize> Add for Matrix<S, R, C>
+where
+    S: Semiring,
+{
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        let mut out = self;
+        for (a, b) in out.rows.as_flattened_mut().iter_mut().zip(other.rows.as_flattened()) {
+            *a = a.plus(*b);
+        }
+        out
+    }
+}
+
+impl<S, const R: usize, const C: usize> Index<(usize, usize)> for Matrix<S, R, C> {
+    type Output = S;
+
+    fn index(&self, (row, col): (usize, usize)) -> &S {
+        &self.rows[row][col]
+    }
+}
+
+impl<S, const R: usize, const C: usize> IndexMut<(usize, usize)> for Matrix<S, R, C> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut S {
+        &mut self.rows[row][col]
+    }
+}
+
+impl<'a, S> SliceMatrix<'a, S>
+where
+    S: Semiring,
+{
+    pub fn new(data: &'a mut [S], rows: usize, cols: usize) -> Self {
+        if data.len() != rows * cols {
+            panic!("slice length does not match matrix dimensions")
+        }
+        SliceMatrix { data, rows, cols }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn as_slice(&self) -> &[S] {
+        self.data
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> S {
+        self[(row, col)]
+    }
+
+    pub fn fill_zero(&mut self) {
+        self.data.fill(S::zero());
+    }
+
+    pub fn fill_identity(&mut self) {
+        self.assert_square();
+        identity_raw(self.data, self.rows);
+    }
+
+    /// Writes `self * other` into `out`.
+    pub fn mul_into(&self, other: &SliceMatrix<'_, S>, out: &mut SliceMatrix<'_, S>) {
+        if self.cols != other.rows || out.rows != self.rows || out.cols != other.cols {
+            panic!("matrix dimensions do not match")
+        }
+        mul_raw(self.data, other.data, out.data, self.rows, self.cols, other.cols);
+    }
+
+    /// Writes `self` to the power of `exp` into `out`, using `scratch` of at
+    /// least `2 * n * n` elements in place of heap temporaries.
+    pub fn pow_into(&self, mut exp: u32, out: &mut SliceMatrix<'_, S>, scratch: &mut [S]) {
+        self.assert_square();
+        let n = self.rows;
+        if out.rows != n || out.cols != n {
+            panic!("matrix dimensions do not match")
+        }
+        if scratch.len() < 2 * n * n {
+            panic!("scratch space must hold two matrices")
+        }
+        let (base, rest) = scratch.split_at_mut(n * n);
+        let tmp = &mut rest[..n * n];
+        base.copy_from_slice(self.data);
+        identity_raw(out.data, n);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                mul_raw(out.data, base, tmp, n, n, n);
+                out.data.copy_from_slice(tmp);
+            }
+            mul_raw(base, base, tmp, n, n, n);
+            base.copy_from_slice(tmp);
+            exp >>= 1;
+        }
+    }
+
+    /// Replaces `self` with its Kleene star, see `Matrix::star`. Returns
+    /// `false` and leaves `self` partially updated if some star is missing.
+    pub fn star_in_place(&mut self) -> bool {
+        self.assert_square();
+        star_raw(self.data, self.rows)
+    }
+
+    fn assert_square(&self) {
+        if self.rows != self.cols {
+            panic!("matrix is not square")
+        }
+    }
+}
+
+impl<S> Index<(usize, usize)> for SliceMatrix<'_, S> {
+    type Output = S;
+
+    fn index(&self, (row, col): (usize, usize)) -> &S {
+        if col >= self.cols {
+            panic!("column index out of bounds")
+        }
+        &self.data[row * self.cols + col]
+    }
+}
+
+impl<S> IndexMut<(usize, usize)> for SliceMatrix<'_, S> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut S {
+        if col >= self.cols {
+            panic!("column index out of bounds")
+        }
+        &mut self.data[row * self.cols + col]
+    }
+}
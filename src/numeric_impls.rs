@@ -1,4 +1,7 @@
-use crate::traits::{Zero, Negate, CheckedAdd, CheckedSub, CheckedMul, CheckedDiv, Unsigned};
+use core::cmp::Ordering;
+
+use crate::traits::{Zero, Negate, CheckedNeg, CheckedAdd, CheckedSub, CheckedMul, CheckedDiv, Unsigned, Bounded, WideningMul};
+use crate::wide::{I256, U256};
 use crate::{DoubleInfiniteNumber, SingleInfiniteNumber};
 
 macro_rules! impl_double_infinity_numeric_primitives_traits {
@@ -15,6 +18,12 @@ macro_rules! impl_double_infinity_numeric_primitives_traits {
             }
         }
 
+        impl CheckedNeg for $t {
+            fn checked_neg(self) -> Option<$t> {
+                self.checked_neg()
+            }
+        }
+
         impl CheckedAdd for $t {
             fn checked_add(self, other: $t) -> Option<$t> {
                 self.checked_add(other)
@@ -39,15 +48,20 @@ macro_rules! impl_double_infinity_numeric_primitives_traits {
             }
         }
 
+        impl Bounded for $t {
+            const MIN_VALUE: Self = <$t>::MIN;
+            const MAX_VALUE: Self = <$t>::MAX;
+        }
+
         impl From<$t> for DoubleInfiniteNumber<$t> {
             fn from(value: $t) -> Self {
                 DoubleInfiniteNumber::Finite(value)
             }
         }
 
-        impl Into<$t> for DoubleInfiniteNumber<$t> {
-            fn into(self) -> $t {
-                match self {
+        impl From<DoubleInfiniteNumber<$t>> for $t {
+            fn from(value: DoubleInfiniteNumber<$t>) -> $t {
+                match value {
                     DoubleInfiniteNumber::Finite(val) => val,
                     DoubleInfiniteNumber::PosInfinity => <$t>::MAX,
                     DoubleInfiniteNumber::NegInfinity => <$t>::MIN,
@@ -89,15 +103,20 @@ macro_rules! impl_single_infinity_numeric_primitives_traits {
             }
         }
 
+        impl Bounded for $t {
+            const MIN_VALUE: Self = <$t>::MIN;
+            const MAX_VALUE: Self = <$t>::MAX;
+        }
+
         impl From<$t> for SingleInfiniteNumber<$t> {
             fn from(value: $t) -> Self {
                 SingleInfiniteNumber::Finite(value)
             }
         }
 
-        impl Into<$t> for SingleInfiniteNumber<$t> {
-            fn into(self) -> $t {
-                match self {
+        impl From<SingleInfiniteNumber<$t>> for $t {
+            fn from(value: SingleInfiniteNumber<$t>) -> $t {
+                match value {
                     SingleInfiniteNumber::Finite(val) => val,
                     SingleInfiniteNumber::Infinity => <$t>::MAX,
                 }
@@ -106,6 +125,82 @@ macro_rules! impl_single_infinity_numeric_primitives_traits {
     };
 }
 
+// compares a single-bounded number with a double-bounded one whose signed
+// type holds every value of the unsigned type
+macro_rules! impl_cross_infinity_comparisons {
+    ($u:ty => $($i:ty),+) => {
+        $(
+            impl PartialEq<DoubleInfiniteNumber<$i>> for SingleInfiniteNumber<$u> {
+                fn eq(&self, other: &DoubleInfiniteNumber<$i>) -> bool {
+                    self.partial_cmp(other) == Some(Ordering::Equal)
+                }
+            }
+
+            impl PartialEq<SingleInfiniteNumber<$u>> for DoubleInfiniteNumber<$i> {
+                fn eq(&self, other: &SingleInfiniteNumber<$u>) -> bool {
+                    other == self
+                }
+            }
+
+            impl PartialOrd<DoubleInfiniteNumber<$i>> for SingleInfiniteNumber<$u> {
+                fn partial_cmp(&self, other: &DoubleInfiniteNumber<$i>) -> Option<Ordering> {
+                    Some(match (self, other) {
+                        (SingleInfiniteNumber::Finite(a), DoubleInfiniteNumber::Finite(b)) => <$i>::from(*a).cmp(b),
+                        (SingleInfiniteNumber::Infinity, DoubleInfiniteNumber::PosInfinity) => Ordering::Equal,
+                        (SingleInfiniteNumber::Infinity, _) => Ordering::Greater,
+                        (SingleInfiniteNumber::Finite(_), DoubleInfiniteNumber::PosInfinity) => Ordering::Less,
+                        (SingleInfiniteNumber::Finite(_), DoubleInfiniteNumber::NegInfinity) => Ordering::Greater,
+                    })
+                }
+            }
+
+            impl PartialOrd<SingleInfiniteNumber<$u>> for DoubleInfiniteNumber<$i> {
+                fn partial_cmp(&self, other: &SingleInfiniteNumber<$u>) -> Option<Ordering> {
+                    other.partial_cmp(self).map(Ordering::reverse)
+                }
+            }
+        )+
+    };
+}
+
+// the product of two values, plus a third, always fits in the wider type;
+// division by zero is left to the caller
+macro_rules! impl_widening_mul {
+    ($t:ty, $w:ty) => {
+        impl WideningMul for $t {
+            fn wide_mul_div(self, b: $t, c: $t) -> Result<$t, Ordering> {
+                let wide = self as $w * b as $w / c as $w;
+                <$t>::try_from(wide).map_err(|_| if wide > <$t>::MAX as $w { Ordering::Greater } else { Ordering::Less })
+            }
+
+            fn wide_mul_add(self, b: $t, c: $t) -> Result<$t, Ordering> {
+                let wide = self as $w * b as $w + c as $w;
+                <$t>::try_from(wide).map_err(|_| if wide > <$t>::MAX as $w { Ordering::Greater } else { Ordering::Less })
+            }
+        }
+    };
+}
+
+impl WideningMul for i128 {
+    fn wide_mul_div(self, b: i128, c: i128) -> Result<i128, Ordering> {
+        I256::widening_mul(self, b).div(c).to_i128()
+    }
+
+    fn wide_mul_add(self, b: i128, c: i128) -> Result<i128, Ordering> {
+        I256::widening_mul(self, b).add(I256::from_i128(c)).to_i128()
+    }
+}
+
+impl WideningMul for u128 {
+    fn wide_mul_div(self, b: u128, c: u128) -> Result<u128, Ordering> {
+        U256::widening_mul(self, b).div(c).to_u128().ok_or(Ordering::Greater)
+    }
+
+    fn wide_mul_add(self, b: u128, c: u128) -> Result<u128, Ordering> {
+        U256::widening_mul(self, b).add(U256::from_u128(c)).to_u128().ok_or(Ordering::Greater)
+    }
+}
+
 impl_double_infinity_numeric_primitives_traits!(i8);
 impl_double_infinity_numeric_primitives_traits!(i16);
 impl_double_infinity_numeric_primitives_traits!(i32);
@@ -121,4 +216,18 @@ impl_single_infinity_numeric_primitives_traits!(u8);
 impl_single_infinity_numeric_primitives_traits!(u16);
 impl_single_infinity_numeric_primitives_traits!(u32);
 impl_single_infinity_numeric_primitives_traits!(u64);
-impl_single_infinity_numeric_primitives_traits!(u128);
\ No newline at end of file
+impl_single_infinity_numeric_primitives_traits!(u128);
+
+impl_cross_infinity_comparisons!(u8 => i16, i32, i64, i128);
+impl_cross_infinity_comparisons!(u16 => i32, i64, i128);
+impl_cross_infinity_comparisons!(u32 => i64, i128);
+impl_cross_infinity_comparisons!(u64 => i128);
+
+impl_widening_mul!(i8, i16);
+impl_widening_mul!(i16, i32);
+impl_widening_mul!(i32, i64);
+impl_widening_mul!(i64, i128);
+impl_widening_mul!(u8, u16);
+impl_widening_mul!(u16, u32);
+impl_widening_mul!(u32, u64);
+impl_widening_mul!(u64, u128);
@@ -1,7 +1,7 @@
 use core::ops::{Add,Sub,Mul,Div};
 
 use crate::intfinity::{SingleInfiniteNumber,DoubleInfiniteNumber};
-use crate::traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Negate, Unsigned, Zero};
+use crate::traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Unsigned, Zero};
 
 impl<T> Add for DoubleInfiniteNumber<T>
 where
@@ -41,9 +41,10 @@ where
         match (self, other) {
             // finite - finite
             (DoubleInfiniteNumber::Finite(a), DoubleInfiniteNumber::Finite(b)) => {
+                // only operands of opposite signs overflow, and `0 - MIN` does too
                 a.checked_sub(b)
                     .map_or_else(
-                        || if a > T::zero() { Self::PosInfinity } else { Self::NegInfinity },
+                        || if a > b { Self::PosInfinity } else { Self::NegInfinity },
                         DoubleInfiniteNumber::Finite
                     )
             },
@@ -127,7 +128,7 @@ where
 
 impl<T> Div for DoubleInfiniteNumber<T>
 where
-    T: Copy + Div<Output = T> + PartialOrd + Zero + CheckedDiv + Negate,
+    T: Copy + Div<Output = T> + PartialOrd + Zero + CheckedDiv,
 {
     type Output = Self;
 
@@ -159,8 +160,10 @@ where
                     panic!("division by zero")
                 } else if a > T::zero() {
                     self
+                } else if let DoubleInfiniteNumber::PosInfinity = self {
+                    Self::NegInfinity
                 } else {
-                    self.negate_double_bounded_infinity()
+                    Self::PosInfinity
                 }
             },
             // inf/inf
@@ -213,6 +216,10 @@ where
     }
 }
 
+/// Clamps at zero: `3 - 5` and `x - inf` both give `Finite(0)`. `inf - inf`
+/// gives `Infinity` rather than panicking as an indeterminate form; use
+/// `sub_with` to choose. `checked_sub`, `abs_diff` and `signed_sub` avoid the
+/// clamping.
 impl<T> Sub for SingleInfiniteNumber<T>
 where
     T: Copy + Sub<Output = T> + PartialOrd + Unsigned + CheckedSub + Zero,
@@ -297,4 +304,32 @@ where
     }
 }
 
+impl<T: Unsigned> SingleInfiniteNumber<T> {
+    /// `indeterminate_cmp` against a double-bounded number: `Infinity` against
+    /// `PosInfinity` is `None`.
+    pub fn indeterminate_cmp_double<U>(&self, other: &DoubleInfiniteNumber<U>) -> Option<core::cmp::Ordering>
+    where
+        Self: PartialOrd<DoubleInfiniteNumber<U>>,
+    {
+        match (self, other) {
+            (SingleInfiniteNumber::Infinity, DoubleInfiniteNumber::PosInfinity) => None,
+            _ => self.partial_cmp(other),
+        }
+    }
+}
+
+impl<T> DoubleInfiniteNumber<T> {
+    /// `indeterminate_cmp` against a single-bounded number: `PosInfinity`
+    /// against `Infinity` is `None`.
+    pub fn indeterminate_cmp_single<U: Unsigned>(&self, other: &SingleInfiniteNumber<U>) -> Option<core::cmp::Ordering>
+    where
+        Self: PartialOrd<SingleInfiniteNumber<U>>,
+    {
+        match (self, other) {
+            (DoubleInfiniteNumber::PosInfinity, SingleInfiniteNumber::Infinity) => None,
+            _ => self.partial_cmp(other),
+        }
+    }
+}
+
 
@@ -0,0 +1,101 @@
+use core::cmp::Ordering;
+
+use crate::intfinity::{SingleInfiniteNumber, DoubleInfiniteNumber};
+use crate::traits::Unsigned;
+
+/// Which order to compare by. `Total` is the derived `Ord`, where an infinity
+/// equals itself; `Indeterminate` follows `indeterminate_cmp`, where
+/// comparing an infinity with the same infinity gives no answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CmpPolicy {
+    Total,
+    Indeterminate,
+}
+
+pub trait IndeterminateOrd: Ord + Sized {
+    fn indeterminate_cmp(&self, other: &Self) -> Option<Ordering>;
+
+    fn cmp_with(&self, other: &Self, policy: CmpPolicy) -> Option<Ordering> {
+        match policy {
+            CmpPolicy::Total => Some(self.cmp(other)),
+            CmpPolicy::Indeterminate => self.indeterminate_cmp(other),
+        }
+    }
+
+    /// Smaller of the two, or `None` when they are the same infinity.
+    fn indeterminate_min(self, other: Self) -> Option<Self> {
+        match self.indeterminate_cmp(&other)? {
+            Ordering::Greater => Some(other),
+            _ => Some(self),
+        }
+    }
+
+    /// Greater of the two, or `None` when they are the same infinity.
+    fn indeterminate_max(self, other: Self) -> Option<Self> {
+        match self.indeterminate_cmp(&other)? {
+            Ordering::Less => Some(other),
+            _ => Some(self),
+        }
+    }
+
+    /// Restricts `self` to `[lo, hi]`, where either bound may be infinite.
+    /// Returns `None` if `self` is the same infinity as one of the bounds; use
+    /// `Ord::clamp` to treat that case as equal. Panics if `lo > hi`.
+    fn indeterminate_clamp(self, lo: Self, hi: Self) -> Option<Self> {
+        if lo > hi {
+            panic!("clamp lower bound is greater than upper bound")
+        }
+        if self.indeterminate_cmp(&lo)? == Ordering::Less {
+            return Some(lo);
+        }
+        if self.indeterminate_cmp(&hi)? == Ordering::Greater {
+            return Some(hi);
+        }
+        Some(self)
+    }
+}
+
+impl<T: Ord> IndeterminateOrd for DoubleInfiniteNumber<T> {
+    fn indeterminate_cmp(&self, other: &Self) -> Option<Ordering> {
+        DoubleInfiniteNumber::indeterminate_cmp(self, other)
+    }
+}
+
+impl<T: Ord + Unsigned> IndeterminateOrd for SingleInfiniteNumber<T> {
+    fn indeterminate_cmp(&self, other: &Self) -> Option<Ordering> {
+        SingleInfiniteNumber::indeterminate_cmp(self, other)
+    }
+}
+
+/// Index of the smallest value. Ties, including several copies of the same
+/// infinity, resolve to the first occurrence.
+pub fn argmin<N: IndeterminateOrd>(values: &[N]) -> Option<usize> {
+    let mut best: Option<usize> = None;
+    for (i, value) in values.iter().enumerate() {
+        match best {
+            Some(b) if values[b] <= *value => {}
+            _ => best = Some(i),
+        }
+    }
+    best
+}
+
+/// Index of the greatest value. Ties, including several copies of the same
+/// infinity, resolve to the first occurrence.
+pub fn argmax<N: IndeterminateOrd>(values: &[N]) -> Option<usize> {
+    let mut best: Option<usize> = None;
+    for (i, value) in values.iter().enumerate() {
+        match best {
+            Some(b) if values[b] >= *value => {}
+            _ => best = Some(i),
+        }
+    }
+    best
+}
+
+/// Sorts ascending by `indeterminate_cmp`, placing indeterminate pairs next to
+/// each other. Copies of the same infinity are indistinguishable, so the sort
+/// is not stable.
+pub fn sort_by_indeterminate<N: IndeterminateOrd>(values: &mut [N]) {
+    values.sort_unstable_by(|a, b| a.indeterminate_cmp(b).unwrap_or(Ordering::Equal));
+}
@@ -0,0 +1,135 @@
+// `petgraph::algo::Measure` has a blanket impl, which both enums pick up
+// through their `Default` (finite zero), `PartialOrd` and `Add` impls.
+// `DoubleInfiniteNumber` is no `FloatMeasure`: `bellman_ford` adds weights to
+// the `PosInfinity` distance of unreached vertices, which for a `NegInfinity`
+// weight is the indeterminate `+inf + (-inf)` that `+` panics on.
+use petgraph::algo::{BoundedMeasure, FloatMeasure};
+
+use crate::{DoubleInfiniteNumber, SingleInfiniteNumber};
+
+macro_rules! impl_double_infinity_petgraph_traits {
+    ($t:ty) => {
+        impl DoubleInfiniteNumber<$t> {
+            // `as` truncates towards zero, so only values whose integer part
+            // does not fit are promoted to infinity
+            fn from_petgraph_float(val: f64) -> Self {
+                if val.is_nan() {
+                    panic!("cannot convert NaN to an infinite number")
+                } else if val >= <$t>::MAX as f64 + 1.0 {
+                    DoubleInfiniteNumber::PosInfinity
+                } else if val - <$t>::MIN as f64 <= -1.0 {
+                    DoubleInfiniteNumber::NegInfinity
+                } else {
+                    DoubleInfiniteNumber::Finite(val as $t)
+                }
+            }
+        }
+
+        impl BoundedMeasure for DoubleInfiniteNumber<$t> {
+            fn min() -> Self {
+                DoubleInfiniteNumber::NegInfinity
+            }
+
+            fn max() -> Self {
+                DoubleInfiniteNumber::PosInfinity
+            }
+
+            // reports promotion to infinity, and +inf + (-inf), as overflow
+            fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+                match (self, rhs) {
+                    (DoubleInfiniteNumber::PosInfinity, DoubleInfiniteNumber::NegInfinity) | (DoubleInfiniteNumber::NegInfinity, DoubleInfiniteNumber::PosInfinity) => {
+                        (DoubleInfiniteNumber::PosInfinity, true)
+                    },
+                    (DoubleInfiniteNumber::Finite(_), DoubleInfiniteNumber::Finite(_)) => {
+                        let sum = self + rhs;
+                        (sum, !matches!(sum, DoubleInfiniteNumber::Finite(_)))
+                    },
+                    _ => (self + rhs, false),
+                }
+            }
+
+            fn from_f32(val: f32) -> Self {
+                Self::from_petgraph_float(val as f64)
+            }
+
+            fn from_f64(val: f64) -> Self {
+                Self::from_petgraph_float(val)
+            }
+        }
+    };
+}
+
+macro_rules! impl_single_infinity_petgraph_traits {
+    ($t:ty) => {
+        impl SingleInfiniteNumber<$t> {
+            fn from_petgraph_float(val: f64) -> Self {
+                if val.is_nan() {
+                    panic!("cannot convert NaN to an infinite number")
+                } else if val <= -1.0 {
+                    panic!("cannot convert a negative value to a single-bounded infinite number")
+                } else if val >= <$t>::MAX as f64 + 1.0 {
+                    SingleInfiniteNumber::Infinity
+                } else {
+                    SingleInfiniteNumber::Finite(val as $t)
+                }
+            }
+        }
+
+        impl FloatMeasure for SingleInfiniteNumber<$t> {
+            fn zero() -> Self {
+                SingleInfiniteNumber::Finite(0)
+            }
+
+            fn infinite() -> Self {
+                SingleInfiniteNumber::Infinity
+            }
+
+            fn from_f32(val: f32) -> Self {
+                Self::from_petgraph_float(val as f64)
+            }
+
+            fn from_f64(val: f64) -> Self {
+                Self::from_petgraph_float(val)
+            }
+        }
+
+        impl BoundedMeasure for SingleInfiniteNumber<$t> {
+            fn min() -> Self {
+                SingleInfiniteNumber::Finite(0)
+            }
+
+            fn max() -> Self {
+                SingleInfiniteNumber::Infinity
+            }
+
+            // reports promotion to infinity as overflow
+            fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+                let sum = self + rhs;
+                match (self, rhs, sum) {
+                    (SingleInfiniteNumber::Finite(_), SingleInfiniteNumber::Finite(_), SingleInfiniteNumber::Infinity) => (sum, true),
+                    _ => (sum, false),
+                }
+            }
+
+            fn from_f32(val: f32) -> Self {
+                Self::from_petgraph_float(val as f64)
+            }
+
+            fn from_f64(val: f64) -> Self {
+                Self::from_petgraph_float(val)
+            }
+        }
+    };
+}
+
+impl_double_infinity_petgraph_traits!(i8);
+impl_double_infinity_petgraph_traits!(i16);
+impl_double_infinity_petgraph_traits!(i32);
+impl_double_infinity_petgraph_traits!(i64);
+impl_double_infinity_petgraph_traits!(i128);
+
+impl_single_infinity_petgraph_traits!(u8);
+impl_single_infinity_petgraph_traits!(u16);
+impl_single_infinity_petgraph_traits!(u32);
+impl_single_infinity_petgraph_traits!(u64);
+impl_single_infinity_petgraph_traits!(u128);
@@ -0,0 +1,122 @@
+//! PostgreSQL text syntax for infinite numbers and ranges.
+//!
+//! Values use `infinity` and `-infinity`; on input `+infinity`, `inf`, `+inf`
+//! and `-inf` are also accepted, ignoring case and surrounding whitespace.
+//! Ranges use Postgres's range literals: `[1,5)`, `(,10]` with an omitted
+//! bound for an unbounded side, and `empty`. An explicit `-infinity` or
+//! `infinity` bound reads the same as an omitted one, since `Interval` does
+//! not tell them apart. Discrete ranges are not canonicalized: `(3,7)` is
+//! written back as `(3,7)`, where Postgres would print `[4,7)`.
+
+use core::fmt;
+use core::str::FromStr;
+
+use crate::intfinity::DoubleInfiniteNumber;
+use crate::interval::{BoundType, Interval};
+
+/// A `DoubleInfiniteNumber` read and written as Postgres text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PgValue<T>(pub DoubleInfiniteNumber<T>);
+
+/// An `Interval` read and written as a Postgres range literal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PgRange<T>(pub Interval<T>);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseError {
+    /// Neither an infinity nor a valid `T`.
+    InvalidValue,
+    /// Missing brackets or a wrong number of bounds.
+    InvalidRange,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::InvalidValue => write!(f, "invalid value"),
+            ParseError::InvalidRange => write!(f, "malformed range literal"),
+        }
+    }
+}
+
+impl core::error::Error for ParseError {}
+
+impl<T: fmt::Display> fmt::Display for PgValue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            DoubleInfiniteNumber::Finite(val) => write!(f, "{}", val),
+            DoubleInfiniteNumber::PosInfinity => write!(f, "infinity"),
+            DoubleInfiniteNumber::NegInfinity => write!(f, "-infinity"),
+        }
+    }
+}
+
+impl<T: FromStr> FromStr for PgValue<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let s = s.trim();
+        let is_any = |names: &[&str]| names.iter().any(|name| s.eq_ignore_ascii_case(name));
+        if is_any(&["infinity", "+infinity", "inf", "+inf"]) {
+            Ok(PgValue(DoubleInfiniteNumber::PosInfinity))
+        } else if is_any(&["-infinity", "-inf"]) {
+            Ok(PgValue(DoubleInfiniteNumber::NegInfinity))
+        } else {
+            s.parse().map(|val| PgValue(DoubleInfiniteNumber::Finite(val))).map_err(|_| ParseError::InvalidValue)
+        }
+    }
+}
+
+impl<T: fmt::Display + Copy + Ord> fmt::Display for PgRange<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let range = &self.0;
+        if range.is_empty() {
+            return write!(f, "empty");
+        }
+        write!(f, "{}", if range.lo_type() == BoundType::Closed { '[' } else { '(' })?;
+        if let DoubleInfiniteNumber::Finite(lo) = range.lo() {
+            write!(f, "{}", lo)?;
+        }
+        write!(f, ",")?;
+        if let DoubleInfiniteNumber::Finite(hi) = range.hi() {
+            write!(f, "{}", hi)?;
+        }
+        write!(f, "{}", if range.hi_type() == BoundType::Closed { ']' } else { ')' })
+    }
+}
+
+impl<T: FromStr + Copy + Ord> FromStr for PgRange<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let s = s.trim();
+        if s.eq_ignore_ascii_case("empty") {
+            return Ok(PgRange(Interval::empty()));
+        }
+        let lo_type = match s.chars().next() {
+            Some('[') => BoundType::Closed,
+            Some('(') => BoundType::Open,
+            _ => return Err(ParseError::InvalidRange),
+        };
+        let hi_type = match s.chars().last() {
+            Some(']') if s.len() > 1 => BoundType::Closed,
+            Some(')') if s.len() > 1 => BoundType::Open,
+            _ => return Err(ParseError::InvalidRange),
+        };
+        let (lo, hi) = s[1..s.len() - 1].split_once(',').ok_or(ParseError::InvalidRange)?;
+        if hi.contains(',') {
+            return Err(ParseError::InvalidRange);
+        }
+        let lo = parse_bound(lo, DoubleInfiniteNumber::NegInfinity)?;
+        let hi = parse_bound(hi, DoubleInfiniteNumber::PosInfinity)?;
+        Ok(PgRange(Interval::new(lo, lo_type, hi, hi_type)))
+    }
+}
+
+fn parse_bound<T: FromStr>(s: &str, unbounded: DoubleInfiniteNumber<T>) -> Result<DoubleInfiniteNumber<T>, ParseError> {
+    if s.trim().is_empty() {
+        Ok(unbounded)
+    } else {
+        s.parse::<PgValue<T>>().map(|value| value.0)
+    }
+}
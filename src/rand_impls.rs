@@ -0,0 +1,172 @@
+use rand::distr::uniform::{Error, SampleBorrow, SampleUniform, UniformSampler};
+use rand::distr::Distribution;
+use rand::Rng;
+
+use crate::intfinity::{SingleInfiniteNumber, DoubleInfiniteNumber};
+use crate::traits::Unsigned;
+
+/// Samples finite values from `finite`, replaced by `+inf` with probability
+/// `pos_infinity`, e.g. edge weights where some edges are missing.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WithInfinities<D> {
+    finite: D,
+    pos_infinity: f64,
+}
+
+/// A `WithInfinities` that also samples `-inf`, so it only produces
+/// `DoubleInfiniteNumber`s:
+///
+/// ```compile_fail
+/// use intfinity::SingleInfiniteNumber;
+/// use intfinity::rand_impls::WithInfinities;
+/// use rand::distr::{Distribution, Uniform};
+/// use rand::SeedableRng;
+///
+/// let dist = WithInfinities::new(Uniform::new(0u8, 3).unwrap(), 0.1).with_neg_infinity(0.1);
+/// let _: SingleInfiniteNumber<u8> = dist.sample(&mut rand_chacha::ChaCha8Rng::seed_from_u64(0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WithBothInfinities<D> {
+    finite: D,
+    pos_infinity: f64,
+    neg_infinity: f64,
+}
+
+fn check_probabilities(pos: f64, neg: f64) {
+    if !(0.0..=1.0).contains(&pos) || !(0.0..=1.0).contains(&neg) || pos + neg > 1.0 {
+        panic!("infinity probabilities must lie in [0, 1] and sum to at most 1")
+    }
+}
+
+impl<D> WithInfinities<D> {
+    /// Panics unless `pos_infinity` is a probability.
+    pub fn new(finite: D, pos_infinity: f64) -> Self {
+        check_probabilities(pos_infinity, 0.0);
+        WithInfinities { finite, pos_infinity }
+    }
+
+    /// Also samples `-inf`. Panics unless both are probabilities summing to
+    /// at most 1.
+    pub fn with_neg_infinity(self, neg_infinity: f64) -> WithBothInfinities<D> {
+        check_probabilities(self.pos_infinity, neg_infinity);
+        WithBothInfinities { finite: self.finite, pos_infinity: self.pos_infinity, neg_infinity }
+    }
+}
+
+impl<T, D: Distribution<T>> Distribution<DoubleInfiniteNumber<T>> for WithInfinities<D> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> DoubleInfiniteNumber<T> {
+        let x: f64 = rng.random();
+        if x < self.pos_infinity {
+            DoubleInfiniteNumber::PosInfinity
+        } else {
+            DoubleInfiniteNumber::Finite(self.finite.sample(rng))
+        }
+    }
+}
+
+impl<T: Unsigned, D: Distribution<T>> Distribution<SingleInfiniteNumber<T>> for WithInfinities<D> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> SingleInfiniteNumber<T> {
+        let x: f64 = rng.random();
+        if x < self.pos_infinity {
+            SingleInfiniteNumber::Infinity
+        } else {
+            SingleInfiniteNumber::Finite(self.finite.sample(rng))
+        }
+    }
+}
+
+impl<T, D: Distribution<T>> Distribution<DoubleInfiniteNumber<T>> for WithBothInfinities<D> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> DoubleInfiniteNumber<T> {
+        let x: f64 = rng.random();
+        if x < self.pos_infinity {
+            DoubleInfiniteNumber::PosInfinity
+        } else if x < self.pos_infinity + self.neg_infinity {
+            DoubleInfiniteNumber::NegInfinity
+        } else {
+            DoubleInfiniteNumber::Finite(self.finite.sample(rng))
+        }
+    }
+}
+
+// uniform sampling between finite bounds; an infinite bound has no uniform
+// distribution and is reported as `Error::NonFinite`
+
+#[derive(Debug, Clone, Copy)]
+pub struct UniformDouble<T: SampleUniform>(T::Sampler);
+
+#[derive(Debug, Clone, Copy)]
+pub struct UniformSingle<T: SampleUniform>(T::Sampler);
+
+fn finite_double<T: Copy>(bound: &DoubleInfiniteNumber<T>) -> Result<T, Error> {
+    match bound {
+        DoubleInfiniteNumber::Finite(val) => Ok(*val),
+        _ => Err(Error::NonFinite),
+    }
+}
+
+fn finite_single<T: Copy + Unsigned>(bound: &SingleInfiniteNumber<T>) -> Result<T, Error> {
+    match bound {
+        SingleInfiniteNumber::Finite(val) => Ok(*val),
+        SingleInfiniteNumber::Infinity => Err(Error::NonFinite),
+    }
+}
+
+impl<T: SampleUniform + Copy> SampleUniform for DoubleInfiniteNumber<T> {
+    type Sampler = UniformDouble<T>;
+}
+
+impl<T: SampleUniform + Copy> UniformSampler for UniformDouble<T> {
+    type X = DoubleInfiniteNumber<T>;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let (low, high) = (finite_double(low.borrow())?, finite_double(high.borrow())?);
+        T::Sampler::new(low, high).map(UniformDouble)
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let (low, high) = (finite_double(low.borrow())?, finite_double(high.borrow())?);
+        T::Sampler::new_inclusive(low, high).map(UniformDouble)
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        DoubleInfiniteNumber::Finite(self.0.sample(rng))
+    }
+}
+
+impl<T: SampleUniform + Copy + Unsigned> SampleUniform for SingleInfiniteNumber<T> {
+    type Sampler = UniformSingle<T>;
+}
+
+impl<T: SampleUniform + Copy + Unsigned> UniformSampler for UniformSingle<T> {
+    type X = SingleInfiniteNumber<T>;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let (low, high) = (finite_single(low.borrow())?, finite_single(high.borrow())?);
+        T::Sampler::new(low, high).map(UniformSingle)
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let (low, high) = (finite_single(low.borrow())?, finite_single(high.borrow())?);
+        T::Sampler::new_inclusive(low, high).map(UniformSingle)
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        SingleInfiniteNumber::Finite(self.0.sample(rng))
+    }
+}
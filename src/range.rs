@@ -0,0 +1,112 @@
+use core::ops::{Bound, RangeBounds};
+
+use crate::intfinity::{SingleInfiniteNumber, DoubleInfiniteNumber};
+use crate::traits::{Unsigned, Zero};
+
+/// Range between two infinite numbers, usable wherever `RangeBounds<T>` is
+/// expected, e.g. `BTreeMap::range`. Infinite ends become `Bound::Unbounded`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InfRange<T> {
+    start: Bound<T>,
+    end: Bound<T>,
+}
+
+impl<T> From<DoubleInfiniteNumber<T>> for Bound<T> {
+    fn from(value: DoubleInfiniteNumber<T>) -> Self {
+        match value {
+            DoubleInfiniteNumber::Finite(val) => Bound::Included(val),
+            DoubleInfiniteNumber::PosInfinity | DoubleInfiniteNumber::NegInfinity => Bound::Unbounded,
+        }
+    }
+}
+
+impl<T: Unsigned> From<SingleInfiniteNumber<T>> for Bound<T> {
+    fn from(value: SingleInfiniteNumber<T>) -> Self {
+        match value {
+            SingleInfiniteNumber::Finite(val) => Bound::Included(val),
+            SingleInfiniteNumber::Infinity => Bound::Unbounded,
+        }
+    }
+}
+
+// an infinite number carries no openness, so `Excluded` maps to its value
+impl<T> DoubleInfiniteNumber<T> {
+    pub fn from_lower_bound(bound: Bound<T>) -> Self {
+        match bound {
+            Bound::Included(val) | Bound::Excluded(val) => DoubleInfiniteNumber::Finite(val),
+            Bound::Unbounded => DoubleInfiniteNumber::NegInfinity,
+        }
+    }
+
+    pub fn from_upper_bound(bound: Bound<T>) -> Self {
+        match bound {
+            Bound::Included(val) | Bound::Excluded(val) => DoubleInfiniteNumber::Finite(val),
+            Bound::Unbounded => DoubleInfiniteNumber::PosInfinity,
+        }
+    }
+}
+
+impl<T: Unsigned + Zero> SingleInfiniteNumber<T> {
+    pub fn from_lower_bound(bound: Bound<T>) -> Self {
+        match bound {
+            Bound::Included(val) | Bound::Excluded(val) => SingleInfiniteNumber::Finite(val),
+            Bound::Unbounded => SingleInfiniteNumber::Finite(T::zero()),
+        }
+    }
+
+    pub fn from_upper_bound(bound: Bound<T>) -> Self {
+        match bound {
+            Bound::Included(val) | Bound::Excluded(val) => SingleInfiniteNumber::Finite(val),
+            Bound::Unbounded => SingleInfiniteNumber::Infinity,
+        }
+    }
+}
+
+impl<T> InfRange<T> {
+    /// `lo..=hi`. Like `BTreeMap::range` with inverted bounds, panics if the
+    /// range starts at `+inf` or ends at `-inf`.
+    pub fn new(lo: DoubleInfiniteNumber<T>, hi: DoubleInfiniteNumber<T>) -> Self {
+        Self::check(&lo, &hi);
+        InfRange { start: lo.into(), end: hi.into() }
+    }
+
+    /// `lo..hi`, excluding a finite `hi`. Panics like `InfRange::new`.
+    pub fn half_open(lo: DoubleInfiniteNumber<T>, hi: DoubleInfiniteNumber<T>) -> Self {
+        Self::check(&lo, &hi);
+        let end = match hi {
+            DoubleInfiniteNumber::Finite(val) => Bound::Excluded(val),
+            _ => Bound::Unbounded,
+        };
+        InfRange { start: lo.into(), end }
+    }
+
+    /// `lo..=hi` over single-bounded numbers. Panics if `lo` is infinite.
+    pub fn from_single(lo: SingleInfiniteNumber<T>, hi: SingleInfiniteNumber<T>) -> Self
+    where
+        T: Unsigned,
+    {
+        if let SingleInfiniteNumber::Infinity = lo {
+            panic!("range start is +infinity")
+        }
+        InfRange { start: lo.into(), end: hi.into() }
+    }
+
+    fn check(lo: &DoubleInfiniteNumber<T>, hi: &DoubleInfiniteNumber<T>) {
+        if let DoubleInfiniteNumber::PosInfinity = lo {
+            panic!("range start is +infinity")
+        }
+        if let DoubleInfiniteNumber::NegInfinity = hi {
+            panic!("range end is -infinity")
+        }
+    }
+}
+
+impl<T> RangeBounds<T> for InfRange<T> {
+    fn start_bound(&self) -> Bound<&T> {
+        self.start.as_ref()
+    }
+
+    fn end_bound(&self) -> Bound<&T> {
+        self.end.as_ref()
+    }
+}
@@ -0,0 +1,67 @@
+use alloc::borrow::Cow;
+use alloc::vec;
+
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+use crate::intfinity::{SingleInfiniteNumber, DoubleInfiniteNumber};
+
+// matches the serde encoding: an integer in the backing type's range or one
+// of the infinity strings. 128-bit types only write integers in the 64-bit
+// range and use the `digits` branch, a decimal string, for the rest
+
+fn double_schema(integer: Schema, digits: Option<Schema>) -> Schema {
+    let mut branches = vec![integer];
+    branches.extend(digits);
+    branches.push(json_schema!({ "const": "+infinity" }));
+    branches.push(json_schema!({ "const": "-infinity" }));
+    json_schema!({ "oneOf": branches })
+}
+
+fn single_schema(integer: Schema, digits: Option<Schema>) -> Schema {
+    let mut branches = vec![integer];
+    branches.extend(digits);
+    branches.push(json_schema!({ "const": "+infinity" }));
+    json_schema!({ "oneOf": branches })
+}
+
+macro_rules! impl_json_schema {
+    ($kind:ident, $schema:ident, $t:ty, $format:literal, { $($bounds:tt)* }, $digits:expr) => {
+        impl JsonSchema for $kind<$t> {
+            fn schema_name() -> Cow<'static, str> {
+                concat!(stringify!($kind), "_", $format).into()
+            }
+
+            fn schema_id() -> Cow<'static, str> {
+                concat!("intfinity::", stringify!($kind), "<", stringify!($t), ">").into()
+            }
+
+            fn json_schema(_: &mut SchemaGenerator) -> Schema {
+                $schema(json_schema!({ "type": "integer", "format": $format, $($bounds)* }), $digits)
+            }
+        }
+    };
+}
+
+macro_rules! impl_double_schema {
+    ($($t:ty => $format:literal),*) => {
+        $(impl_json_schema!(DoubleInfiniteNumber, double_schema, $t, $format, { "minimum": <$t>::MIN, "maximum": <$t>::MAX }, None);)*
+    };
+}
+
+macro_rules! impl_single_schema {
+    ($($t:ty => $format:literal),*) => {
+        $(impl_json_schema!(SingleInfiniteNumber, single_schema, $t, $format, { "minimum": 0, "maximum": <$t>::MAX }, None);)*
+    };
+}
+
+impl_double_schema!(i8 => "int8", i16 => "int16", i32 => "int32", i64 => "int64");
+impl_json_schema!(
+    DoubleInfiniteNumber, double_schema, i128, "int128", { "minimum": i64::MIN, "maximum": i64::MAX },
+    Some(json_schema!({ "type": "string", "pattern": "^-?[0-9]+$" }))
+);
+
+impl_single_schema!(u8 => "uint8", u16 => "uint16", u32 => "uint32", u64 => "uint64");
+impl_json_schema!(
+    SingleInfiniteNumber, single_schema, u128, "uint128", { "minimum": 0, "maximum": u64::MAX },
+    Some(json_schema!({ "type": "string", "pattern": "^[0-9]+$" }))
+);
@@ -0,0 +1,178 @@
+use core::ops::Add;
+
+use crate::intfinity::{SingleInfiniteNumber, DoubleInfiniteNumber};
+use crate::traits::{CheckedAdd, Unsigned, Zero};
+use crate::tropical::{MinPlus, MaxPlus, MaxMin};
+
+/// A semiring `(S, plus, times, zero, one)`. Path problems become matrix
+/// products over the matching semiring, see `matrix.rs`.
+pub trait Semiring: Copy + PartialEq {
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn plus(self, other: Self) -> Self;
+    fn times(self, other: Self) -> Self;
+
+    /// Kleene star `one + a + a*a + ...`, or `None` if the semiring is not
+    /// closed for `self`.
+    fn star(self) -> Option<Self> {
+        None
+    }
+}
+
+impl Semiring for bool {
+    fn zero() -> Self {
+        false
+    }
+
+    fn one() -> Self {
+        true
+    }
+
+    fn plus(self, other: Self) -> Self {
+        self || other
+    }
+
+    fn times(self, other: Self) -> Self {
+        self && other
+    }
+
+    fn star(self) -> Option<Self> {
+        Some(true)
+    }
+}
+
+impl<T> Semiring for MinPlus<SingleInfiniteNumber<T>>
+where
+    T: Copy + Ord + Add<Output = T> + Zero + CheckedAdd + Unsigned,
+{
+    fn zero() -> Self {
+        MinPlus::zero()
+    }
+
+    fn one() -> Self {
+        MinPlus::one()
+    }
+
+    fn plus(self, other: Self) -> Self {
+        self + other
+    }
+
+    fn times(self, other: Self) -> Self {
+        self * other
+    }
+
+    // weights are never negative, so going around a cycle never helps
+    fn star(self) -> Option<Self> {
+        Some(MinPlus::one())
+    }
+}
+
+impl<T> Semiring for MinPlus<DoubleInfiniteNumber<T>>
+where
+    T: Copy + Ord + Add<Output = T> + Zero + CheckedAdd,
+{
+    fn zero() -> Self {
+        MinPlus::zero()
+    }
+
+    fn one() -> Self {
+        MinPlus::one()
+    }
+
+    fn plus(self, other: Self) -> Self {
+        self + other
+    }
+
+    fn times(self, other: Self) -> Self {
+        self * other
+    }
+
+    // a negative cycle can be taken arbitrarily often
+    fn star(self) -> Option<Self> {
+        if self >= MinPlus::one() {
+            Some(MinPlus::one())
+        } else {
+            Some(MinPlus(DoubleInfiniteNumber::NegInfinity))
+        }
+    }
+}
+
+impl<T> Semiring for MaxPlus<DoubleInfiniteNumber<T>>
+where
+    T: Copy + Ord + Add<Output = T> + Zero + CheckedAdd,
+{
+    fn zero() -> Self {
+        MaxPlus::zero()
+    }
+
+    fn one() -> Self {
+        MaxPlus::one()
+    }
+
+    fn plus(self, other: Self) -> Self {
+        self + other
+    }
+
+    fn times(self, other: Self) -> Self {
+        self * other
+    }
+
+    // a positive cycle can be taken arbitrarily often
+    fn star(self) -> Option<Self> {
+        if self <= MaxPlus::one() {
+            Some(MaxPlus::one())
+        } else {
+            Some(MaxPlus(DoubleInfiniteNumber::PosInfinity))
+        }
+    }
+}
+
+impl<T> Semiring for MaxMin<SingleInfiniteNumber<T>>
+where
+    T: Copy + Ord + Add<Output = T> + Zero + CheckedAdd + Unsigned,
+{
+    fn zero() -> Self {
+        MaxMin(SingleInfiniteNumber::Finite(T::zero()))
+    }
+
+    fn one() -> Self {
+        MaxMin(SingleInfiniteNumber::Infinity)
+    }
+
+    fn plus(self, other: Self) -> Self {
+        self + other
+    }
+
+    fn times(self, other: Self) -> Self {
+        self * other
+    }
+
+    fn star(self) -> Option<Self> {
+        Some(Self::one())
+    }
+}
+
+impl<T> Semiring for MaxMin<DoubleInfiniteNumber<T>>
+where
+    T: Copy + Ord + Add<Output = T> + Zero + CheckedAdd,
+{
+    fn zero() -> Self {
+        MaxMin(DoubleInfiniteNumber::NegInfinity)
+    }
+
+    fn one() -> Self {
+        MaxMin(DoubleInfiniteNumber::PosInfinity)
+    }
+
+    fn plus(self, other: Self) -> Self {
+        self + other
+    }
+
+    fn times(self, other: Self) -> Self {
+        self * other
+    }
+
+    fn star(self) -> Option<Self> {
+        Some(Self::one())
+    }
+}
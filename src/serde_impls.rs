@@ -0,0 +1,155 @@
+use core::fmt;
+use core::marker::PhantomData;
+use core::str::FromStr;
+
+use serde::de::{self, Deserialize, Deserializer, IntoDeserializer, Unexpected, Visitor};
+use serde::{Serialize, Serializer};
+
+use crate::intfinity::{SingleInfiniteNumber, DoubleInfiniteNumber};
+
+// finite values are plain integers and infinities the strings `Display`
+// writes, so deserializing needs a self-describing format such as JSON.
+// Formats like JSON read integers past the 64-bit range back as floats, so
+// 128-bit values outside it are written as decimal strings instead
+
+const POS_INFINITY: &str = "+infinity";
+const NEG_INFINITY: &str = "-infinity";
+
+trait Integer: Copy + fmt::Display + FromStr + Serialize + for<'de> Deserialize<'de> {
+    fn fits_64_bits(self) -> bool {
+        true
+    }
+}
+
+impl Integer for i8 {}
+impl Integer for i16 {}
+impl Integer for i32 {}
+impl Integer for i64 {}
+impl Integer for u8 {}
+impl Integer for u16 {}
+impl Integer for u32 {}
+impl Integer for u64 {}
+
+impl Integer for i128 {
+    fn fits_64_bits(self) -> bool {
+        i64::try_from(self).is_ok()
+    }
+}
+
+impl Integer for u128 {
+    fn fits_64_bits(self) -> bool {
+        u64::try_from(self).is_ok()
+    }
+}
+
+fn serialize_finite<T: Integer, S: Serializer>(val: T, serializer: S) -> Result<S::Ok, S::Error> {
+    if val.fits_64_bits() {
+        val.serialize(serializer)
+    } else {
+        serializer.collect_str(&val)
+    }
+}
+
+/// Reads an integer as `T` or an infinity string; `allow_negative` is false
+/// for `SingleInfiniteNumber`.
+struct InfinityVisitor<T> {
+    allow_negative: bool,
+    marker: PhantomData<T>,
+}
+
+enum Parsed<T> {
+    Finite(T),
+    PosInfinity,
+    NegInfinity,
+}
+
+macro_rules! visit_integers {
+    ($($method:ident: $t:ty),*) => {
+        $(
+            fn $method<E: de::Error>(self, v: $t) -> Result<Self::Value, E> {
+                T::deserialize(v.into_deserializer()).map(Parsed::Finite)
+            }
+        )*
+    };
+}
+
+impl<'de, T: Integer> Visitor<'de> for InfinityVisitor<T> {
+    type Value = Parsed<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.allow_negative {
+            write!(f, "an integer, \"{}\" or \"{}\"", POS_INFINITY, NEG_INFINITY)
+        } else {
+            write!(f, "an integer or \"{}\"", POS_INFINITY)
+        }
+    }
+
+    visit_integers!(visit_i64: i64, visit_u64: u64, visit_i128: i128, visit_u128: u128);
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        match v {
+            POS_INFINITY => Ok(Parsed::PosInfinity),
+            NEG_INFINITY if self.allow_negative => Ok(Parsed::NegInfinity),
+            _ => match v.parse::<T>() {
+                // only values `serialize_finite` writes as strings
+                Ok(val) if !val.fits_64_bits() => Ok(Parsed::Finite(val)),
+                _ => Err(E::invalid_value(Unexpected::Str(v), &self)),
+            },
+        }
+    }
+}
+
+macro_rules! impl_double_serde {
+    ($($t:ty),*) => {
+        $(
+            impl Serialize for DoubleInfiniteNumber<$t> {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    match self {
+                        DoubleInfiniteNumber::Finite(val) => serialize_finite(*val, serializer),
+                        DoubleInfiniteNumber::PosInfinity => serializer.serialize_str(POS_INFINITY),
+                        DoubleInfiniteNumber::NegInfinity => serializer.serialize_str(NEG_INFINITY),
+                    }
+                }
+            }
+
+            impl<'de> Deserialize<'de> for DoubleInfiniteNumber<$t> {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let visitor = InfinityVisitor { allow_negative: true, marker: PhantomData };
+                    Ok(match deserializer.deserialize_any(visitor)? {
+                        Parsed::Finite(val) => DoubleInfiniteNumber::Finite(val),
+                        Parsed::PosInfinity => DoubleInfiniteNumber::PosInfinity,
+                        Parsed::NegInfinity => DoubleInfiniteNumber::NegInfinity,
+                    })
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_single_serde {
+    ($($t:ty),*) => {
+        $(
+            impl Serialize for SingleInfiniteNumber<$t> {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    match self {
+                        SingleInfiniteNumber::Finite(val) => serialize_finite(*val, serializer),
+                        SingleInfiniteNumber::Infinity => serializer.serialize_str(POS_INFINITY),
+                    }
+                }
+            }
+
+            impl<'de> Deserialize<'de> for SingleInfiniteNumber<$t> {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let visitor = InfinityVisitor { allow_negative: false, marker: PhantomData };
+                    Ok(match deserializer.deserialize_any(visitor)? {
+                        Parsed::Finite(val) => SingleInfiniteNumber::Finite(val),
+                        _ => SingleInfiniteNumber::Infinity,
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_double_serde!(i8, i16, i32, i64, i128);
+impl_single_serde!(u8, u16, u32, u64, u128);
@@ -0,0 +1,118 @@
+use core::ops::{Add, Sub, Mul, Div};
+
+use crate::traits::Infinite;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    Add,
+    Sub,
+    Mul,
+}
+
+/// Where an infinite value came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Origin<N> {
+    /// The infinity was passed in, or carried through from such a value.
+    Input,
+    /// Finite operands overflowed in `op` and were promoted to infinity.
+    Overflow { op: Op, lhs: N, rhs: N },
+    /// A finite division overflowed, e.g. `MIN / -1`.
+    Division { lhs: N, rhs: N },
+}
+
+/// Wraps an infinite number and records where any infinity in it came from.
+/// The operators behave exactly like those of `N`; the plain enums are left
+/// untouched, so this costs nothing unless used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Traced<N> {
+    value: N,
+    origin: Origin<N>,
+}
+
+impl<N: Infinite> Traced<N> {
+    pub fn new(value: N) -> Self {
+        Traced { value, origin: Origin::Input }
+    }
+
+    pub fn value(&self) -> N {
+        self.value
+    }
+
+    /// `None` while the value is finite.
+    pub fn origin(&self) -> Option<Origin<N>> {
+        if self.value.is_finite() {
+            None
+        } else {
+            Some(self.origin)
+        }
+    }
+
+    // an infinite result either comes from promoting finite operands, or is
+    // carried through from an infinite operand, preferring the left one
+    fn trace(self, rhs: Self, value: N, promoted: Origin<N>) -> Self {
+        let origin = if value.is_finite() {
+            Origin::Input
+        } else if self.value.is_finite() && rhs.value.is_finite() {
+            promoted
+        } else if self.value.is_infinite() {
+            self.origin
+        } else {
+            rhs.origin
+        };
+        Traced { value, origin }
+    }
+}
+
+impl<N: Infinite> From<N> for Traced<N> {
+    fn from(value: N) -> Self {
+        Traced::new(value)
+    }
+}
+
+impl<N> Add for Traced<N>
+where
+    N: Infinite + Add<Output = N>,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let promoted = Origin::Overflow { op: Op::Add, lhs: self.value, rhs: rhs.value };
+        self.trace(rhs, self.value + rhs.value, promoted)
+    }
+}
+
+impl<N> Sub for Traced<N>
+where
+    N: Infinite + Sub<Output = N>,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        let promoted = Origin::Overflow { op: Op::Sub, lhs: self.value, rhs: rhs.value };
+        self.trace(rhs, self.value - rhs.value, promoted)
+    }
+}
+
+impl<N> Mul for Traced<N>
+where
+    N: Infinite + Mul<Output = N>,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let promoted = Origin::Overflow { op: Op::Mul, lhs: self.value, rhs: rhs.value };
+        self.trace(rhs, self.value * rhs.value, promoted)
+    }
+}
+
+impl<N> Div for Traced<N>
+where
+    N: Infinite + Div<Output = N>,
+{
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        let promoted = Origin::Division { lhs: self.value, rhs: rhs.value };
+        self.trace(rhs, self.value / rhs.value, promoted)
+    }
+}
@@ -5,10 +5,16 @@ pub trait Zero {
     }
 }
 
+/// Plain negation, which overflows for a signed `MIN`; infinity-aware code
+/// uses `CheckedNeg` instead.
 pub trait Negate {
     fn negate(self) -> Self;
 }
 
+pub trait CheckedNeg: Sized {
+    fn checked_neg(self) -> Option<Self>;
+}
+
 
 pub trait CheckedAdd: Sized {
     fn checked_add(self, other: Self) -> Option<Self>;
@@ -27,4 +33,43 @@ pub trait CheckedDiv: Sized {
     fn checked_div(self, other: Self) -> Option<Self>;
 }
 
-pub trait Unsigned {}
\ No newline at end of file
+pub trait Unsigned {}
+
+/// `a * b / c` and `a * b + c` computed in a double-width type, so only the
+/// final result can overflow. `Err` holds the direction of that overflow.
+pub trait WideningMul: Sized {
+    fn wide_mul_div(self, b: Self, c: Self) -> Result<Self, core::cmp::Ordering>;
+    fn wide_mul_add(self, b: Self, c: Self) -> Result<Self, core::cmp::Ordering>;
+}
+
+pub trait Bounded {
+    const MIN_VALUE: Self;
+    const MAX_VALUE: Self;
+}
+
+/// Common interface of `SingleInfiniteNumber` and `DoubleInfiniteNumber`, so
+/// algorithms can be written once over either of them. `min`/`max` come from
+/// the `Ord` supertrait.
+pub trait Infinite: Copy + Ord {
+    type Value: Copy;
+
+    fn infinity() -> Self;
+    fn finite(value: Self::Value) -> Self;
+    fn as_finite(&self) -> Option<Self::Value>;
+
+    /// Infinity-aware addition, `None` for the indeterminate form `+inf + (-inf)`.
+    fn try_add(self, other: Self) -> Option<Self>;
+
+    fn is_finite(&self) -> bool {
+        self.as_finite().is_some()
+    }
+
+    fn is_infinite(&self) -> bool {
+        !self.is_finite()
+    }
+}
+
+/// An `Infinite` type that is also unbounded below.
+pub trait NegInfinite: Infinite {
+    fn neg_infinity() -> Self;
+}
@@ -0,0 +1,138 @@
+use core::iter::{Product, Sum};
+use core::ops::{Add, Mul};
+
+use crate::traits::{Infinite, NegInfinite, Zero};
+
+/// Min-plus (tropical) semiring: `a + b` is `min(a, b)` and `a * b` is the
+/// infinity-aware sum. `+inf` is the additive identity and absorbs in `*`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MinPlus<N>(pub N);
+
+/// Max-plus (arctic) semiring: `a + b` is `max(a, b)` and `a * b` is the
+/// infinity-aware sum. `-inf` is the additive identity and absorbs in `*`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MaxPlus<N>(pub N);
+
+/// Max-min (bottleneck) semiring used for widest paths: `a + b` is
+/// `max(a, b)` and `a * b` is `min(a, b)`. The identities are the least value
+/// of `N` and infinity; see the `Semiring` impls in `semiring.rs`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MaxMin<N>(pub N);
+
+impl<N> MinPlus<N>
+where
+    N: Infinite,
+    N::Value: Zero,
+{
+    pub fn zero() -> Self {
+        MinPlus(N::infinity())
+    }
+
+    pub fn one() -> Self {
+        MinPlus(N::finite(N::Value::zero()))
+    }
+}
+
+impl<N> MaxPlus<N>
+where
+    N: NegInfinite,
+    N::Value: Zero,
+{
+    pub fn zero() -> Self {
+        MaxPlus(N::neg_infinity())
+    }
+
+    pub fn one() -> Self {
+        MaxPlus(N::finite(N::Value::zero()))
+    }
+}
+
+impl<N: Infinite> Add for MinPlus<N> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        MinPlus(self.0.min(other.0))
+    }
+}
+
+impl<N: Infinite> Mul for MinPlus<N> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        // the only failing sum is +inf + (-inf), where +inf has to absorb
+        MinPlus(self.0.try_add(other.0).unwrap_or(N::infinity()))
+    }
+}
+
+impl<N: NegInfinite> Add for MaxPlus<N> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        MaxPlus(self.0.max(other.0))
+    }
+}
+
+impl<N: NegInfinite> Mul for MaxPlus<N> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        // the only failing sum is +inf + (-inf), where -inf has to absorb
+        MaxPlus(self.0.try_add(other.0).unwrap_or(N::neg_infinity()))
+    }
+}
+
+impl<N: Infinite> Add for MaxMin<N> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        MaxMin(self.0.max(other.0))
+    }
+}
+
+impl<N: Infinite> Mul for MaxMin<N> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        MaxMin(self.0.min(other.0))
+    }
+}
+
+impl<N> Sum for MinPlus<N>
+where
+    N: Infinite,
+    N::Value: Zero,
+{
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, x| acc + x)
+    }
+}
+
+impl<N> Product for MinPlus<N>
+where
+    N: Infinite,
+    N::Value: Zero,
+{
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |acc, x| acc * x)
+    }
+}
+
+impl<N> Sum for MaxPlus<N>
+where
+    N: NegInfinite,
+    N::Value: Zero,
+{
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, x| acc + x)
+    }
+}
+
+impl<N> Product for MaxPlus<N>
+where
+    N: NegInfinite,
+    N::Value: Zero,
+{
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |acc, x| acc * x)
+    }
+}
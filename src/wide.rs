@@ -0,0 +1,124 @@
+// 256-bit helpers backing the fused operations on 128-bit types. Only what
+// `a * b / c` and `a * b + c` need is implemented.
+
+use core::cmp::Ordering;
+
+const LOW_HALF: u128 = u64::MAX as u128;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct U256 {
+    hi: u128,
+    lo: u128,
+}
+
+impl U256 {
+    pub(crate) fn from_u128(value: u128) -> Self {
+        U256 { hi: 0, lo: value }
+    }
+
+    pub(crate) fn widening_mul(a: u128, b: u128) -> Self {
+        let (a_hi, a_lo) = (a >> 64, a & LOW_HALF);
+        let (b_hi, b_lo) = (b >> 64, b & LOW_HALF);
+
+        let lo_lo = a_lo * b_lo;
+        let lo_hi = a_lo * b_hi;
+        let hi_lo = a_hi * b_lo;
+        let hi_hi = a_hi * b_hi;
+
+        let mid = (lo_lo >> 64) + (lo_hi & LOW_HALF) + (hi_lo & LOW_HALF);
+        U256 {
+            hi: hi_hi + (lo_hi >> 64) + (hi_lo >> 64) + (mid >> 64),
+            lo: (lo_lo & LOW_HALF) | (mid << 64),
+        }
+    }
+
+    // callers only add values whose sum fits in 256 bits
+    pub(crate) fn add(self, other: Self) -> Self {
+        let (lo, carry) = self.lo.overflowing_add(other.lo);
+        U256 { hi: self.hi + other.hi + carry as u128, lo }
+    }
+
+    // callers only subtract a smaller value
+    pub(crate) fn sub(self, other: Self) -> Self {
+        let (lo, borrow) = self.lo.overflowing_sub(other.lo);
+        U256 { hi: self.hi - other.hi - borrow as u128, lo }
+    }
+
+    /// Truncating division by a non-zero divisor.
+    pub(crate) fn div(self, divisor: u128) -> Self {
+        let mut quotient = U256 { hi: 0, lo: 0 };
+        let mut rem: u128 = 0;
+        for i in (0..256).rev() {
+            let bit = if i >= 128 { (self.hi >> (i - 128)) & 1 } else { (self.lo >> i) & 1 };
+            // the shifted-out top bit means the remainder is at least 2^128
+            let carry = rem >> 127;
+            rem = (rem << 1) | bit;
+            if carry == 1 || rem >= divisor {
+                rem = rem.wrapping_sub(divisor);
+                if i >= 128 {
+                    quotient.hi |= 1 << (i - 128);
+                } else {
+                    quotient.lo |= 1 << i;
+                }
+            }
+        }
+        quotient
+    }
+
+    pub(crate) fn to_u128(self) -> Option<u128> {
+        if self.hi == 0 {
+            Some(self.lo)
+        } else {
+            None
+        }
+    }
+}
+
+/// Sign and magnitude, so that division truncates toward zero like `i128`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct I256 {
+    negative: bool,
+    magnitude: U256,
+}
+
+impl I256 {
+    pub(crate) fn from_i128(value: i128) -> Self {
+        I256 { negative: value < 0, magnitude: U256::from_u128(value.unsigned_abs()) }
+    }
+
+    pub(crate) fn widening_mul(a: i128, b: i128) -> Self {
+        I256 {
+            negative: (a < 0) != (b < 0),
+            magnitude: U256::widening_mul(a.unsigned_abs(), b.unsigned_abs()),
+        }
+    }
+
+    pub(crate) fn add(self, other: Self) -> Self {
+        if self.negative == other.negative {
+            return I256 { negative: self.negative, magnitude: self.magnitude.add(other.magnitude) };
+        }
+        if self.magnitude >= other.magnitude {
+            I256 { negative: self.negative, magnitude: self.magnitude.sub(other.magnitude) }
+        } else {
+            I256 { negative: other.negative, magnitude: other.magnitude.sub(self.magnitude) }
+        }
+    }
+
+    pub(crate) fn div(self, divisor: i128) -> Self {
+        I256 {
+            negative: self.negative != (divisor < 0),
+            magnitude: self.magnitude.div(divisor.unsigned_abs()),
+        }
+    }
+
+    /// `Err` holds the direction of the overflow.
+    pub(crate) fn to_i128(self) -> Result<i128, Ordering> {
+        let min_magnitude = i128::MIN.unsigned_abs();
+        match (self.negative, self.magnitude.to_u128()) {
+            (false, Some(m)) if m < min_magnitude => Ok(m as i128),
+            (true, Some(m)) if m <= min_magnitude => Ok((m as i128).wrapping_neg()),
+            (false, _) => Err(Ordering::Greater),
+            (true, _) => Err(Ordering::Less),
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use arbitrary::{Arbitrary, Unstructured};
+use intfinity::{SingleInfiniteNumber, DoubleInfiniteNumber};
+
+#[test]
+fn test_generates_every_variant() {
+    let bytes: Vec<u8> = (0..=255).cycle().take(4096).collect();
+    let mut u = Unstructured::new(&bytes);
+    let (mut neg, mut finite, mut pos) = (0, 0, 0);
+    for _ in 0..200 {
+        match DoubleInfiniteNumber::<i32>::arbitrary(&mut u).unwrap() {
+            DoubleInfiniteNumber::NegInfinity => neg += 1,
+            DoubleInfiniteNumber::Finite(_) => finite += 1,
+            DoubleInfiniteNumber::PosInfinity => pos += 1,
+        }
+    }
+    assert!(neg > 0 && pos > 0);
+    assert!(finite > neg + pos);
+}
+
+#[test]
+fn test_single_generation() {
+    let mut u = Unstructured::new(&[0, 1, 7, 0, 0, 0]);
+    assert_eq!(SingleInfiniteNumber::<u32>::arbitrary(&mut u).unwrap(), SingleInfiniteNumber::Infinity);
+    assert_eq!(SingleInfiniteNumber::<u32>::arbitrary(&mut u).unwrap(), SingleInfiniteNumber::Finite(7));
+}
+
+#[test]
+fn test_exhausted_input_still_produces_values() {
+    let mut u = Unstructured::new(&[]);
+    assert!(DoubleInfiniteNumber::<i64>::arbitrary(&mut u).is_ok());
+}
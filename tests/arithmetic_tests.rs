@@ -0,0 +1,116 @@
+use intfinity::{SingleInfiniteNumber, DoubleInfiniteNumber};
+
+type D = DoubleInfiniteNumber<i8>;
+type S = SingleInfiniteNumber<u8>;
+
+const POS: D = DoubleInfiniteNumber::PosInfinity;
+const NEG: D = DoubleInfiniteNumber::NegInfinity;
+const INF: S = SingleInfiniteNumber::Infinity;
+
+fn d(v: i8) -> D {
+    DoubleInfiniteNumber::new(v)
+}
+
+fn s(v: u8) -> S {
+    SingleInfiniteNumber::new(v)
+}
+
+#[test]
+fn test_finite_bounds() {
+    assert_eq!(D::MAX_FINITE, d(127));
+    assert_eq!(D::MIN_FINITE, d(-128));
+    assert_eq!(S::MAX_FINITE, s(255));
+    assert_eq!(S::MIN_FINITE, s(0));
+}
+
+#[test]
+fn test_double_checked() {
+    assert_eq!(d(100).checked_add(d(20)), Some(d(120)));
+    assert_eq!(d(100).checked_add(d(100)), None);
+    assert_eq!(POS.checked_add(d(1)), Some(POS));
+    assert_eq!(POS.checked_add(NEG), None);
+    assert_eq!(d(-100).checked_sub(d(100)), None);
+    assert_eq!(NEG.checked_sub(NEG), None);
+    assert_eq!(d(1).checked_sub(POS), Some(NEG));
+    assert_eq!(d(16).checked_mul(d(8)), None);
+    assert_eq!(d(0).checked_mul(NEG), None);
+    assert_eq!(d(-2).checked_mul(NEG), Some(POS));
+    assert_eq!(d(-128).checked_div(d(-1)), None);
+    assert_eq!(d(1).checked_div(d(0)), None);
+    assert_eq!(POS.checked_div(d(0)), None);
+    assert_eq!(POS.checked_div(NEG), None);
+    assert_eq!(d(5).checked_div(POS), Some(d(0)));
+    assert_eq!(NEG.checked_div(d(-2)), Some(POS));
+}
+
+#[test]
+fn test_double_overflowing() {
+    assert_eq!(d(100).overflowing_add(d(100)), (POS, true));
+    assert_eq!(d(-100).overflowing_add(d(-100)), (NEG, true));
+    assert_eq!(POS.overflowing_add(d(1)), (POS, false));
+    assert_eq!(d(1).overflowing_add(d(2)), (d(3), false));
+    assert_eq!(d(-100).overflowing_sub(d(100)), (NEG, true));
+    assert_eq!(d(-16).overflowing_mul(d(16)), (NEG, true));
+    assert_eq!(d(-128).overflowing_div(d(-1)), (POS, true));
+    assert_eq!(NEG.overflowing_mul(NEG), (POS, false));
+}
+
+#[test]
+fn test_double_saturating() {
+    assert_eq!(d(100).saturating_add(d(100)), d(127));
+    assert_eq!(d(-100).saturating_sub(d(100)), d(-128));
+    assert_eq!(d(-16).saturating_mul(d(16)), d(-128));
+    assert_eq!(d(-128).saturating_div(d(-1)), d(127));
+    assert_eq!(POS.saturating_add(d(1)), POS);
+    assert_eq!(d(4).saturating_mul(d(5)), d(20));
+}
+
+#[test]
+fn test_single_families() {
+    assert_eq!(s(200).checked_add(s(100)), None);
+    assert_eq!(s(200).overflowing_add(s(100)), (INF, true));
+    assert_eq!(s(200).saturating_add(s(100)), s(255));
+    assert_eq!(INF.overflowing_add(s(1)), (INF, false));
+
+    assert_eq!(s(3).checked_sub(s(5)), None);
+    assert_eq!(s(3).checked_sub(INF), None);
+    assert_eq!(INF.checked_sub(INF), None);
+    assert_eq!(INF.checked_sub(s(5)), Some(INF));
+    assert_eq!(s(3).overflowing_sub(s(5)), (s(0), true));
+    assert_eq!(s(3).overflowing_sub(INF), (s(0), true));
+    assert_eq!(s(5).overflowing_sub(s(3)), (s(2), false));
+    assert_eq!(s(3).saturating_sub(s(5)), s(0));
+
+    assert_eq!(s(16).checked_mul(s(16)), None);
+    assert_eq!(s(0).checked_mul(INF), None);
+    assert_eq!(s(16).saturating_mul(s(16)), s(255));
+    assert_eq!(s(16).overflowing_mul(s(16)), (INF, true));
+
+    assert_eq!(s(7).checked_div(s(0)), None);
+    assert_eq!(INF.checked_div(INF), None);
+    assert_eq!(s(7).checked_div(s(2)), Some(s(3)));
+    assert_eq!(s(7).overflowing_div(INF), (s(0), false));
+    assert_eq!(INF.saturating_div(s(2)), INF);
+}
+
+#[test]
+fn test_wrapping() {
+    assert_eq!(D::MAX_FINITE.wrapping_add(D::new(1)), D::MIN_FINITE);
+    assert_eq!(D::MIN_FINITE.wrapping_sub(D::new(1)), D::MAX_FINITE);
+    assert_eq!(D::new(64).wrapping_mul(D::new(2)), D::new(-128));
+    assert_eq!(D::new(1).wrapping_add(D::PosInfinity), D::PosInfinity);
+    assert_eq!(D::new(1).wrapping_sub(D::PosInfinity), D::NegInfinity);
+    assert_eq!(D::new(-2).wrapping_mul(D::PosInfinity), D::NegInfinity);
+
+    assert_eq!(S::MAX_FINITE.wrapping_add(S::new(1)), S::new(0));
+    assert_eq!(S::new(1).wrapping_sub(S::new(2)), S::MAX_FINITE);
+    assert_eq!(S::new(16).wrapping_mul(S::new(16)), S::new(0));
+    assert_eq!(S::Infinity.wrapping_sub(S::new(3)), S::Infinity);
+    assert_eq!(SingleInfiniteNumber::new(u128::MAX).wrapping_add(SingleInfiniteNumber::new(2)), SingleInfiniteNumber::new(1));
+}
+
+#[test]
+#[should_panic(expected = "indeterminate form")]
+fn test_wrapping_keeps_indeterminate_forms() {
+    let _ = D::PosInfinity.wrapping_sub(D::PosInfinity);
+}
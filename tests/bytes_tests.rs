@@ -0,0 +1,61 @@
+use std::mem::{align_of, size_of};
+
+use intfinity::{SingleInfiniteNumber, DoubleInfiniteNumber};
+use intfinity::bytes::{PackedI32, PackedI128, PackedU64, PackedU128};
+
+#[test]
+fn test_documented_encoding() {
+    assert_eq!(DoubleInfiniteNumber::new(0x0102_0304i32).to_le_bytes(), [0, 4, 3, 2, 1]);
+    assert_eq!(DoubleInfiniteNumber::new(0x0102_0304i32).to_be_bytes(), [0, 1, 2, 3, 4]);
+    assert_eq!(DoubleInfiniteNumber::<i32>::PosInfinity.to_le_bytes(), [1, 0, 0, 0, 0]);
+    assert_eq!(DoubleInfiniteNumber::<i32>::NegInfinity.to_be_bytes(), [2, 0, 0, 0, 0]);
+    assert_eq!(SingleInfiniteNumber::<u16>::Infinity.to_le_bytes(), [1, 0, 0]);
+    assert_eq!(SingleInfiniteNumber::new(0xabu8).to_be_bytes(), [0, 0xab]);
+}
+
+#[test]
+fn test_round_trips() {
+    for x in [DoubleInfiniteNumber::NegInfinity, DoubleInfiniteNumber::new(i64::MIN), DoubleInfiniteNumber::new(-1), DoubleInfiniteNumber::new(i64::MAX), DoubleInfiniteNumber::PosInfinity] {
+        assert_eq!(DoubleInfiniteNumber::<i64>::from_le_bytes(x.to_le_bytes()), Some(x));
+        assert_eq!(DoubleInfiniteNumber::<i64>::from_be_bytes(x.to_be_bytes()), Some(x));
+    }
+    for x in [SingleInfiniteNumber::new(0u32), SingleInfiniteNumber::new(u32::MAX), SingleInfiniteNumber::Infinity] {
+        assert_eq!(SingleInfiniteNumber::<u32>::from_le_bytes(x.to_le_bytes()), Some(x));
+        assert_eq!(SingleInfiniteNumber::<u32>::from_be_bytes(x.to_be_bytes()), Some(x));
+    }
+    for x in [DoubleInfiniteNumber::new(i128::MIN), DoubleInfiniteNumber::new(i128::MAX), DoubleInfiniteNumber::NegInfinity] {
+        assert_eq!(DoubleInfiniteNumber::<i128>::from_le_bytes(x.to_le_bytes()), Some(x));
+        assert_eq!(DoubleInfiniteNumber::<i128>::from_be_bytes(x.to_be_bytes()), Some(x));
+    }
+    for x in [SingleInfiniteNumber::new(0u128), SingleInfiniteNumber::new(u128::MAX), SingleInfiniteNumber::Infinity] {
+        assert_eq!(SingleInfiniteNumber::<u128>::from_le_bytes(x.to_le_bytes()), Some(x));
+        assert_eq!(SingleInfiniteNumber::<u128>::from_be_bytes(x.to_be_bytes()), Some(x));
+    }
+}
+
+#[test]
+fn test_rejects_invalid_bytes() {
+    assert_eq!(DoubleInfiniteNumber::<i16>::from_le_bytes([3, 0, 0]), None);
+    assert_eq!(DoubleInfiniteNumber::<i16>::from_le_bytes([1, 0, 1]), None);
+    assert_eq!(SingleInfiniteNumber::<u16>::from_le_bytes([2, 0, 0]), None);
+    assert_eq!(DoubleInfiniteNumber::<i16>::from_le_bytes([0, 0, 0]), Some(DoubleInfiniteNumber::new(0)));
+}
+
+#[test]
+fn test_packed_layout() {
+    assert_eq!(size_of::<PackedI32>(), 5);
+    assert_eq!(align_of::<PackedI32>(), 1);
+    assert_eq!(size_of::<PackedI128>(), 17);
+    assert_eq!(size_of::<PackedU128>(), 17);
+    assert_eq!(align_of::<PackedU128>(), 1);
+    assert_eq!(size_of::<[PackedU64; 4]>(), 36);
+}
+
+#[test]
+fn test_packed_round_trip() {
+    let x = DoubleInfiniteNumber::new(-7i32);
+    assert_eq!(PackedI32::from(x).get(), Some(x));
+    assert_eq!(PackedI32::new(DoubleInfiniteNumber::NegInfinity).get(), Some(DoubleInfiniteNumber::NegInfinity));
+    assert_eq!(PackedU64::new(SingleInfiniteNumber::Infinity).get(), Some(SingleInfiniteNumber::Infinity));
+    assert_eq!(PackedU128::new(SingleInfiniteNumber::new(u128::MAX)).get(), Some(SingleInfiniteNumber::new(u128::MAX)));
+}
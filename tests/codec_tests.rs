@@ -0,0 +1,104 @@
+use intfinity::{SingleInfiniteNumber, DoubleInfiniteNumber};
+use intfinity::codec::DecodeError;
+
+macro_rules! double_round_trips {
+    ($($name:ident: $t:ty),*) => {
+        $(
+            #[test]
+            fn $name() {
+                let values = [
+                    DoubleInfiniteNumber::NegInfinity,
+                    DoubleInfiniteNumber::new(<$t>::MIN),
+                    DoubleInfiniteNumber::new(-1),
+                    DoubleInfiniteNumber::new(0),
+                    DoubleInfiniteNumber::new(1),
+                    DoubleInfiniteNumber::new(<$t>::MAX),
+                    DoubleInfiniteNumber::PosInfinity,
+                ];
+                for x in values {
+                    let mut buf = [0xaa; DoubleInfiniteNumber::<$t>::MAX_ENCODED_LEN];
+                    let len = x.encode_into(&mut buf);
+                    assert_eq!(len, x.encoded_len());
+                    assert_eq!(DoubleInfiniteNumber::<$t>::decode(&buf), Ok((x, len)));
+                }
+                let mut buf = [0; DoubleInfiniteNumber::<$t>::MAX_ENCODED_LEN];
+                assert_eq!(DoubleInfiniteNumber::new(<$t>::MIN).encode_into(&mut buf), buf.len());
+            }
+        )*
+    };
+}
+
+macro_rules! single_round_trips {
+    ($($name:ident: $t:ty),*) => {
+        $(
+            #[test]
+            fn $name() {
+                let values = [
+                    SingleInfiniteNumber::new(0),
+                    SingleInfiniteNumber::new(127),
+                    SingleInfiniteNumber::new(128),
+                    SingleInfiniteNumber::new(<$t>::MAX),
+                    SingleInfiniteNumber::Infinity,
+                ];
+                for x in values {
+                    let mut buf = [0xaa; SingleInfiniteNumber::<$t>::MAX_ENCODED_LEN];
+                    let len = x.encode_into(&mut buf);
+                    assert_eq!(len, x.encoded_len());
+                    assert_eq!(SingleInfiniteNumber::<$t>::decode(&buf), Ok((x, len)));
+                }
+                let mut buf = [0; SingleInfiniteNumber::<$t>::MAX_ENCODED_LEN];
+                assert_eq!(SingleInfiniteNumber::new(<$t>::MAX).encode_into(&mut buf), buf.len());
+            }
+        )*
+    };
+}
+
+double_round_trips!(test_round_trip_i8: i8, test_round_trip_i16: i16, test_round_trip_i32: i32, test_round_trip_i64: i64, test_round_trip_i128: i128);
+single_round_trips!(test_round_trip_u8: u8, test_round_trip_u16: u16, test_round_trip_u32: u32, test_round_trip_u64: u64, test_round_trip_u128: u128);
+
+#[test]
+fn test_documented_encoding() {
+    let mut buf = [0; 8];
+    assert_eq!(DoubleInfiniteNumber::<i32>::NegInfinity.encode_into(&mut buf), 1);
+    assert_eq!(buf[0], 2);
+    assert_eq!(DoubleInfiniteNumber::new(-1i32).encode_into(&mut buf), 2);
+    assert_eq!(&buf[..2], &[0, 1]);
+    assert_eq!(DoubleInfiniteNumber::new(64i32).encode_into(&mut buf), 3);
+    assert_eq!(&buf[..3], &[0, 0x80, 0x01]);
+    assert_eq!(SingleInfiniteNumber::new(300u32).encode_into(&mut buf), 3);
+    assert_eq!(&buf[..3], &[0, 0xac, 0x02]);
+}
+
+#[test]
+fn test_decodes_a_stream() {
+    let mut buf = [0; 16];
+    let mut at = 0;
+    for x in [DoubleInfiniteNumber::new(-300i64), DoubleInfiniteNumber::PosInfinity, DoubleInfiniteNumber::new(7)] {
+        at += x.encode_into(&mut buf[at..]);
+    }
+    let (a, n) = DoubleInfiniteNumber::<i64>::decode(&buf[..at]).unwrap();
+    let (b, m) = DoubleInfiniteNumber::<i64>::decode(&buf[n..at]).unwrap();
+    let (c, k) = DoubleInfiniteNumber::<i64>::decode(&buf[n + m..at]).unwrap();
+    assert_eq!((a, b, c), (DoubleInfiniteNumber::new(-300), DoubleInfiniteNumber::PosInfinity, DoubleInfiniteNumber::new(7)));
+    assert_eq!(n + m + k, at);
+}
+
+#[test]
+fn test_decode_errors() {
+    assert_eq!(DoubleInfiniteNumber::<i32>::decode(&[]), Err(DecodeError::UnexpectedEnd));
+    assert_eq!(DoubleInfiniteNumber::<i32>::decode(&[0]), Err(DecodeError::UnexpectedEnd));
+    assert_eq!(DoubleInfiniteNumber::<i32>::decode(&[0, 0x80]), Err(DecodeError::UnexpectedEnd));
+    assert_eq!(DoubleInfiniteNumber::<i32>::decode(&[3]), Err(DecodeError::InvalidTag(3)));
+    assert_eq!(SingleInfiniteNumber::<u32>::decode(&[2]), Err(DecodeError::InvalidTag(2)));
+    assert_eq!(SingleInfiniteNumber::<u8>::decode(&[0, 0x80, 0x02]), Err(DecodeError::Overflow));
+    assert_eq!(DoubleInfiniteNumber::<i8>::decode(&[0, 0x80, 0x02]), Err(DecodeError::Overflow));
+    let too_long = [0, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x04];
+    assert_eq!(SingleInfiniteNumber::<u128>::decode(&too_long), Err(DecodeError::Overflow));
+}
+
+#[test]
+#[should_panic(expected = "buffer too small for the encoded value")]
+fn test_encode_into_short_buffer_panics() {
+    let mut buf = [0; 2];
+    SingleInfiniteNumber::new(u32::MAX).encode_into(&mut buf);
+}
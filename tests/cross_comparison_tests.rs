@@ -0,0 +1,39 @@
+use std::cmp::Ordering;
+
+use intfinity::{SingleInfiniteNumber, DoubleInfiniteNumber};
+
+#[test]
+fn test_cross_equality() {
+    assert!(SingleInfiniteNumber::new(7u32) == DoubleInfiniteNumber::new(7i64));
+    assert!(DoubleInfiniteNumber::new(7i64) == SingleInfiniteNumber::new(7u32));
+    assert!(SingleInfiniteNumber::<u8>::Infinity == DoubleInfiniteNumber::<i16>::PosInfinity);
+    assert!(SingleInfiniteNumber::new(255u8) != DoubleInfiniteNumber::new(-1i16));
+    assert!(SingleInfiniteNumber::<u64>::Infinity != DoubleInfiniteNumber::new(i128::MAX));
+}
+
+#[test]
+fn test_cross_ordering() {
+    let budget = DoubleInfiniteNumber::new(100i64);
+    assert!(SingleInfiniteNumber::new(40u32) < budget);
+    assert!(SingleInfiniteNumber::<u32>::Infinity > budget);
+    assert!(budget < SingleInfiniteNumber::<u32>::Infinity);
+    assert!(SingleInfiniteNumber::new(u32::MAX) > DoubleInfiniteNumber::new(-5i64));
+    assert!(SingleInfiniteNumber::new(u32::MAX) < DoubleInfiniteNumber::<i64>::PosInfinity);
+}
+
+#[test]
+fn test_every_single_value_exceeds_neg_infinity() {
+    assert!(SingleInfiniteNumber::new(0u16) > DoubleInfiniteNumber::<i32>::NegInfinity);
+    assert!(SingleInfiniteNumber::<u16>::Infinity > DoubleInfiniteNumber::<i32>::NegInfinity);
+    assert!(DoubleInfiniteNumber::<i128>::NegInfinity < SingleInfiniteNumber::new(0u64));
+}
+
+#[test]
+fn test_mixed_indeterminate_cmp() {
+    let inf = SingleInfiniteNumber::<u32>::Infinity;
+    assert_eq!(inf.indeterminate_cmp_double(&DoubleInfiniteNumber::<i64>::PosInfinity), None);
+    assert_eq!(inf.indeterminate_cmp_double(&DoubleInfiniteNumber::<i64>::NegInfinity), Some(Ordering::Greater));
+    assert_eq!(SingleInfiniteNumber::new(3u32).indeterminate_cmp_double(&DoubleInfiniteNumber::new(3i64)), Some(Ordering::Equal));
+    assert_eq!(DoubleInfiniteNumber::<i64>::PosInfinity.indeterminate_cmp_single(&inf), None);
+    assert_eq!(DoubleInfiniteNumber::new(-1i64).indeterminate_cmp_single(&SingleInfiniteNumber::new(0u32)), Some(Ordering::Less));
+}
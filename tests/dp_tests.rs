@@ -0,0 +1,97 @@
+use intfinity::{SingleInfiniteNumber, DoubleInfiniteNumber};
+use intfinity::dp::{edit_distance, knapsack, DpTable, EditCosts, Relax};
+
+type Cost = SingleInfiniteNumber<u32>;
+
+fn c(v: u32) -> Cost {
+    SingleInfiniteNumber::new(v)
+}
+
+#[test]
+fn test_min_assign() {
+    let mut x = SingleInfiniteNumber::<u32>::Infinity;
+    assert!(x.min_assign(c(5)));
+    assert_eq!(x, c(5));
+    assert!(!x.min_assign(c(7)));
+    assert!(!x.min_assign(c(5)));
+    assert!(!x.min_assign(SingleInfiniteNumber::Infinity));
+    assert_eq!(x, c(5));
+}
+
+#[test]
+fn test_max_assign() {
+    let mut x = DoubleInfiniteNumber::<i32>::NegInfinity;
+    assert!(x.max_assign(DoubleInfiniteNumber::new(-3)));
+    assert!(!x.max_assign(DoubleInfiniteNumber::new(-4)));
+    assert!(x.max_assign(DoubleInfiniteNumber::PosInfinity));
+    assert_eq!(x, DoubleInfiniteNumber::PosInfinity);
+}
+
+#[test]
+fn test_table_starts_at_infinity() {
+    let mut cells = [c(0); 6];
+    let mut table = DpTable::new(&mut cells, 2, 3);
+    assert!(table.as_slice().iter().all(|&x| x == SingleInfiniteNumber::Infinity));
+    table[(1, 2)] = c(4);
+    assert!(table[(1, 2)].min_assign(c(1)));
+    assert_eq!(table.get(1, 2), c(1));
+    assert_eq!((table.rows(), table.cols()), (2, 3));
+}
+
+#[test]
+#[should_panic(expected = "slice length does not match table dimensions")]
+fn test_table_rejects_wrong_length() {
+    let mut cells = [c(0); 5];
+    let _ = DpTable::new(&mut cells, 2, 3);
+}
+
+#[test]
+fn test_unit_edit_distance() {
+    let costs = EditCosts { insert: c(1), delete: c(1), substitute: c(1) };
+    let (a, b) = (b"kitten", b"sitting");
+    let mut cells = vec![c(0); (a.len() + 1) * (b.len() + 1)];
+    assert_eq!(edit_distance(a, b, costs, &mut cells), c(3));
+
+    let mut cells = vec![c(0); 1];
+    assert_eq!(edit_distance::<u8, Cost>(&[], &[], costs, &mut cells), c(0));
+}
+
+#[test]
+fn test_weighted_edit_distance() {
+    let costs = EditCosts { insert: c(2), delete: c(2), substitute: c(3) };
+    let (a, b) = (b"abc", b"adc");
+    let mut cells = vec![c(0); 16];
+    assert_eq!(edit_distance(a, b, costs, &mut cells), c(3));
+
+    // substituting is dearer than deleting and inserting
+    let costs = EditCosts { insert: c(1), delete: c(1), substitute: c(5) };
+    assert_eq!(edit_distance(a, b, costs, &mut cells), c(2));
+}
+
+#[test]
+fn test_edit_distance_with_forbidden_operations() {
+    let costs = EditCosts { insert: SingleInfiniteNumber::Infinity, delete: c(1), substitute: SingleInfiniteNumber::Infinity };
+    let mut cells = vec![c(0); 15];
+    assert_eq!(edit_distance(b"abcd", b"bd", costs, &mut cells), c(2));
+    let mut cells = vec![c(0); 9];
+    assert_eq!(edit_distance(b"ab", b"ba", costs, &mut cells), SingleInfiniteNumber::Infinity);
+}
+
+#[test]
+fn test_knapsack() {
+    let items = [(1u32, 1), (3, 4), (4, 5), (5, 7)];
+    let mut cells = vec![c(0); 18];
+    assert_eq!(knapsack(&items, 7, &mut cells), 9);
+    // value 2 cannot be reached exactly
+    assert_eq!(cells[2], SingleInfiniteNumber::Infinity);
+    assert_eq!(cells[9], c(7));
+
+    assert_eq!(knapsack(&items, 0, &mut cells), 0);
+}
+
+#[test]
+fn test_knapsack_with_double_infinite_numbers() {
+    let items = [(10i64, 60), (20, 100), (30, 120)];
+    let mut cells = vec![DoubleInfiniteNumber::new(0); 281];
+    assert_eq!(knapsack(&items, 50, &mut cells), 220);
+}
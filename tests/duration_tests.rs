@@ -0,0 +1,66 @@
+use core::time::Duration;
+
+use intfinity::SingleInfiniteNumber;
+use intfinity::duration::{Deadline, InfDuration};
+
+#[test]
+fn test_conversions() {
+    assert_eq!(InfDuration::from(Duration::from_millis(1500)), InfDuration::from_millis(1500));
+    assert_eq!(InfDuration::from(Duration::MAX), InfDuration::INFINITE);
+    assert_eq!(InfDuration::from(None::<Duration>), InfDuration::INFINITE);
+    assert_eq!(InfDuration::from(Some(Duration::from_secs(2))), InfDuration::from_secs(2));
+    assert_eq!(Option::<Duration>::from(InfDuration::from_nanos(7)), Some(Duration::from_nanos(7)));
+    assert_eq!(InfDuration::INFINITE.to_duration(), None);
+    assert_eq!(InfDuration::from_secs(2).as_nanos(), SingleInfiniteNumber::new(2_000_000_000));
+    assert!(InfDuration::from_secs(u64::MAX).is_infinite());
+}
+
+#[test]
+fn test_arithmetic() {
+    let one = InfDuration::from_secs(1);
+    assert_eq!(one + one, InfDuration::from_secs(2));
+    assert_eq!(one + InfDuration::INFINITE, InfDuration::INFINITE);
+    assert_eq!(InfDuration::from_nanos(u64::MAX) + InfDuration::from_nanos(1), InfDuration::INFINITE);
+    assert_eq!(one - InfDuration::from_secs(3), InfDuration::ZERO);
+    assert_eq!(InfDuration::INFINITE - one, InfDuration::INFINITE);
+    assert_eq!(one + Duration::from_millis(500), InfDuration::from_millis(1500));
+    assert_eq!(one - Duration::MAX, InfDuration::ZERO);
+}
+
+#[test]
+fn test_min_picks_the_shorter_timeout() {
+    assert_eq!(InfDuration::INFINITE.min(InfDuration::from_secs(5)), InfDuration::from_secs(5));
+    assert_eq!(InfDuration::from_secs(1).min(InfDuration::from_secs(5)), InfDuration::from_secs(1));
+    assert!(InfDuration::ZERO < InfDuration::INFINITE);
+}
+
+#[test]
+fn test_deadline_from_a_tick_source() {
+    let mut ticks = 1_000u64;
+    let deadline = InfDuration::from_nanos(500).deadline(ticks);
+    assert_eq!(deadline, Deadline::at(1_500));
+    assert_eq!(deadline.remaining(ticks), InfDuration::from_nanos(500));
+    assert!(!deadline.is_expired(ticks));
+
+    ticks += 800;
+    assert_eq!(deadline.remaining(ticks), InfDuration::ZERO);
+    assert!(deadline.is_expired(ticks));
+}
+
+#[test]
+fn test_infinite_deadline() {
+    let deadline = InfDuration::INFINITE.deadline(42);
+    assert_eq!(deadline, Deadline::NEVER);
+    assert_eq!(deadline.remaining(u64::MAX), InfDuration::INFINITE);
+    assert!(!deadline.is_expired(u64::MAX));
+    assert_eq!(Deadline::at(5).min(Deadline::NEVER), Deadline::at(5));
+}
+
+#[test]
+fn test_checked_deadline() {
+    assert_eq!(InfDuration::from_nanos(10).checked_deadline(5), Some(Deadline::at(15)));
+    assert_eq!(InfDuration::INFINITE.checked_deadline(5), Some(Deadline::NEVER));
+    assert_eq!(InfDuration::from_nanos(10).checked_deadline(u64::MAX), None);
+    assert_eq!(InfDuration::from_nanos(10).deadline(u64::MAX), Deadline::NEVER);
+    assert_eq!(Deadline::at(9).ticks(), SingleInfiniteNumber::new(9));
+}
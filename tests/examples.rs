@@ -46,9 +46,9 @@ impl Graph {
         let mut min = SingleInfiniteNumber::Infinity;
         let mut min_index = 0;
 
-        for v in 0..self.vertices {
-            if !in_mst.contains(&v) && key[v] < min {
-                min = key[v];
+        for (v, &k) in key.iter().enumerate().take(self.vertices) {
+            if !in_mst.contains(&v) && k < min {
+                min = k;
                 min_index = v;
             }
         }
@@ -80,9 +80,9 @@ impl Graph {
         let mut min = u32::MAX;
         let mut min_index = 0;
 
-        for v in 0..self.vertices {
-            if !in_mst.contains(&v) && key[v] < min {
-                min = key[v];
+        for (v, &k) in key.iter().enumerate().take(self.vertices) {
+            if !in_mst.contains(&v) && k < min {
+                min = k;
                 min_index = v;
             }
         }
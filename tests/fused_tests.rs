@@ -0,0 +1,67 @@
+use intfinity::{SingleInfiniteNumber, DoubleInfiniteNumber};
+
+fn d64(v: i64) -> DoubleInfiniteNumber<i64> {
+    DoubleInfiniteNumber::new(v)
+}
+
+fn d128(v: i128) -> DoubleInfiniteNumber<i128> {
+    DoubleInfiniteNumber::new(v)
+}
+
+fn u128s(v: u128) -> SingleInfiniteNumber<u128> {
+    SingleInfiniteNumber::Finite(v)
+}
+
+#[test]
+fn test_mul_div_avoids_intermediate_overflow() {
+    // the plain operators promote on the product
+    assert_eq!(d64(i64::MAX) * d64(4) / d64(8), DoubleInfiniteNumber::PosInfinity);
+    assert_eq!(d64(i64::MAX).mul_div(d64(4), d64(8)), d64(i64::MAX / 2));
+    assert_eq!(d64(i64::MIN).mul_div(d64(3), d64(-3)), DoubleInfiniteNumber::PosInfinity);
+    assert_eq!(d64(i64::MIN).mul_div(d64(3), d64(3)), d64(i64::MIN));
+    assert_eq!(d64(-7).mul_div(d64(1), d64(2)), d64(-3));
+    assert_eq!(d64(i64::MAX).mul_div(d64(3), d64(2)), DoubleInfiniteNumber::PosInfinity);
+    assert_eq!(d64(i64::MAX).mul_div(d64(-3), d64(2)), DoubleInfiniteNumber::NegInfinity);
+}
+
+#[test]
+fn test_mul_div_with_infinities() {
+    assert_eq!(d64(5).mul_div(d64(i64::MAX), DoubleInfiniteNumber::PosInfinity), d64(0));
+    assert_eq!(DoubleInfiniteNumber::NegInfinity.mul_div(d64(2), d64(-1)), DoubleInfiniteNumber::PosInfinity);
+}
+
+#[test]
+#[should_panic(expected = "division by zero")]
+fn test_mul_div_by_zero() {
+    let _ = d64(1).mul_div(d64(1), d64(0));
+}
+
+#[test]
+fn test_mul_add() {
+    assert_eq!(d64(i64::MAX).mul_add(d64(2), d64(i64::MIN)), d64(i64::MAX - 1));
+    assert_eq!(d64(i64::MAX).mul_add(d64(2), d64(-1)), DoubleInfiniteNumber::PosInfinity);
+    assert_eq!(d64(i64::MIN).mul_add(d64(1), d64(-1)), DoubleInfiniteNumber::NegInfinity);
+    assert_eq!(d64(1 << 32).mul_add(d64(1 << 31), d64(-(1 << 62))), d64(1 << 62));
+    assert_eq!(d64(3).mul_add(d64(4), DoubleInfiniteNumber::NegInfinity), DoubleInfiniteNumber::NegInfinity);
+    assert_eq!(SingleInfiniteNumber::new(u8::MAX).mul_add(SingleInfiniteNumber::new(1), SingleInfiniteNumber::new(1)), SingleInfiniteNumber::Infinity);
+}
+
+#[test]
+fn test_128_bit_backings() {
+    assert_eq!(d128(i128::MAX).mul_div(d128(i128::MAX), d128(i128::MAX)), d128(i128::MAX));
+    assert_eq!(d128(i128::MIN).mul_div(d128(i128::MIN), d128(i128::MIN)), d128(i128::MIN));
+    assert_eq!(d128(i128::MIN).mul_div(d128(1), d128(-1)), DoubleInfiniteNumber::PosInfinity);
+    assert_eq!(d128(i128::MIN).mul_div(d128(-1), d128(-1)), d128(i128::MIN));
+    assert_eq!(d128(i128::MAX).mul_div(d128(6), d128(-7)), d128(-(i128::MAX / 7 * 6)));
+    assert_eq!(d128(i128::MAX).mul_add(d128(-1), d128(-1)), d128(i128::MIN));
+    assert_eq!(d128(i128::MAX).mul_add(d128(2), d128(i128::MIN)), d128(i128::MAX - 1));
+    assert_eq!(d128(i128::MAX).mul_add(d128(2), d128(-1)), DoubleInfiniteNumber::PosInfinity);
+    assert_eq!(d128(i128::MIN).mul_add(d128(2), d128(1)), DoubleInfiniteNumber::NegInfinity);
+    assert_eq!(d128(i128::MAX).mul_add(d128(-2), d128(i128::MAX)), d128(-i128::MAX));
+
+    assert_eq!(u128s(u128::MAX).mul_div(u128s(u128::MAX), u128s(u128::MAX)), u128s(u128::MAX));
+    assert_eq!(u128s(u128::MAX).mul_div(u128s(3), u128s(4)), u128s(u128::MAX / 4 * 3 + 2));
+    assert_eq!(u128s(u128::MAX).mul_div(u128s(2), u128s(1)), SingleInfiniteNumber::Infinity);
+    assert_eq!(u128s(1 << 100).mul_add(u128s(1 << 27), u128s(u128::MAX)), SingleInfiniteNumber::Infinity);
+    assert_eq!(u128s(1 << 100).mul_add(u128s(1 << 27), u128s(5)), u128s((1 << 127) + 5));
+}
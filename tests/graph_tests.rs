@@ -0,0 +1,180 @@
+use intfinity::{SingleInfiniteNumber, DoubleInfiniteNumber};
+use intfinity::graph::{bellman_ford, dijkstra, floyd_warshall, johnson, kruskal, prim, AdjacencyList, Csr};
+
+type Dist = SingleInfiniteNumber<u32>;
+type SignedDist = DoubleInfiniteNumber<i64>;
+
+const INF: Dist = SingleInfiniteNumber::Infinity;
+const POS: SignedDist = DoubleInfiniteNumber::PosInfinity;
+const NEG: SignedDist = DoubleInfiniteNumber::NegInfinity;
+
+fn d(v: u32) -> Dist {
+    SingleInfiniteNumber::new(v)
+}
+
+fn sd(v: i64) -> SignedDist {
+    DoubleInfiniteNumber::new(v)
+}
+
+fn sample_graph() -> AdjacencyList<u32> {
+    let mut g = AdjacencyList::new(5);
+    g.add_edge(0, 1, 4);
+    g.add_edge(0, 2, 1);
+    g.add_edge(2, 1, 2);
+    g.add_edge(1, 3, 5);
+    g
+}
+
+#[test]
+fn test_dijkstra_adjacency_list() {
+    let dist: Vec<Dist> = dijkstra(&sample_graph(), 0);
+    assert_eq!(dist, vec![d(0), d(3), d(1), d(8), INF]);
+}
+
+#[test]
+fn test_dijkstra_csr_matches_adjacency_list() {
+    let csr = Csr::from_edges(5, &[(1, 3, 5u32), (0, 1, 4), (2, 1, 2), (0, 2, 1)]);
+    let dist: Vec<Dist> = dijkstra(&csr, 0);
+    assert_eq!(dist, dijkstra::<_, Dist>(&sample_graph(), 0));
+}
+
+#[test]
+fn test_dijkstra_max_weight_edge_is_still_reachable() {
+    let mut g = AdjacencyList::new(3);
+    g.add_edge(0, 1, u32::MAX);
+    g.add_edge(1, 2, 1);
+    let dist: Vec<Dist> = dijkstra(&g, 0);
+    assert_eq!(dist, vec![d(0), d(u32::MAX), INF]);
+}
+
+#[test]
+fn test_bellman_ford_negative_weights() {
+    let mut g = AdjacencyList::new(4);
+    g.add_edge(0, 1, 4i64);
+    g.add_edge(0, 2, 5);
+    g.add_edge(1, 2, -3);
+    g.add_edge(2, 3, 2);
+    let dist: Vec<SignedDist> = bellman_ford(&g, 0);
+    assert_eq!(dist, vec![sd(0), sd(4), sd(1), sd(3)]);
+}
+
+#[test]
+fn test_bellman_ford_marks_negative_cycle_reachable_vertices() {
+    let mut g = AdjacencyList::new(6);
+    g.add_edge(0, 1, 1i64);
+    g.add_edge(1, 2, -1);
+    g.add_edge(2, 1, -1);
+    g.add_edge(2, 3, 10);
+    g.add_edge(0, 4, 7);
+    // 5 is unreachable
+    let dist: Vec<SignedDist> = bellman_ford(&g, 0);
+    assert_eq!(dist, vec![sd(0), NEG, NEG, NEG, sd(7), POS]);
+}
+
+#[test]
+fn test_floyd_warshall_unsigned() {
+    let all: Vec<Vec<Dist>> = floyd_warshall(&sample_graph());
+    assert_eq!(all[0], vec![d(0), d(3), d(1), d(8), INF]);
+    assert_eq!(all[2], vec![INF, d(2), d(0), d(7), INF]);
+    assert_eq!(all[4], vec![INF, INF, INF, INF, d(0)]);
+}
+
+#[test]
+fn test_floyd_warshall_negative_cycle() {
+    let mut g = AdjacencyList::new(3);
+    g.add_edge(0, 1, -1i64);
+    g.add_edge(1, 0, -1);
+    g.add_edge(1, 2, 3);
+    let all: Vec<Vec<SignedDist>> = floyd_warshall(&g);
+    assert_eq!(all[0], vec![NEG, NEG, NEG]);
+    assert_eq!(all[2], vec![POS, POS, sd(0)]);
+}
+
+#[test]
+fn test_floyd_warshall_empty_graph() {
+    let g: AdjacencyList<u32> = AdjacencyList::new(0);
+    let all: Vec<Vec<Dist>> = floyd_warshall(&g);
+    assert!(all.is_empty());
+}
+
+#[test]
+fn test_johnson_matches_floyd_warshall() {
+    let mut g = AdjacencyList::new(5);
+    g.add_edge(0, 1, 3i64);
+    g.add_edge(0, 2, 8);
+    g.add_edge(0, 4, -4);
+    g.add_edge(1, 3, 1);
+    g.add_edge(1, 4, 7);
+    g.add_edge(2, 1, 4);
+    g.add_edge(3, 0, 2);
+    g.add_edge(3, 2, -5);
+    g.add_edge(4, 3, 6);
+    let expected: Vec<Vec<SignedDist>> = floyd_warshall(&g);
+    assert_eq!(johnson(&g), Some(expected));
+    assert_eq!(johnson(&g).unwrap()[0], vec![sd(0), sd(1), sd(-3), sd(2), sd(-4)]);
+}
+
+#[test]
+fn test_johnson_unreachable_and_negative_cycle() {
+    let mut g = AdjacencyList::new(3);
+    g.add_edge(0, 1, -2i64);
+    assert_eq!(johnson(&g).unwrap()[1], vec![POS, sd(0), POS]);
+
+    g.add_edge(1, 0, 1);
+    assert_eq!(johnson(&g), None);
+}
+
+// the potentials are -100 for vertices 1 and 2, so undoing the reweighting
+// of the 1 -> 2 distance passes through 120 + 100, outside i8
+#[test]
+fn test_johnson_weights_near_max() {
+    let mut g = AdjacencyList::new(4);
+    g.add_edge(0, 1, -100i8);
+    g.add_edge(0, 2, -100);
+    g.add_edge(1, 2, 120);
+    g.add_edge(2, 3, 127);
+    let all = johnson(&g).unwrap();
+    assert_eq!(all[1][2], DoubleInfiniteNumber::new(120));
+    assert_eq!(all[2][3], DoubleInfiniteNumber::new(127));
+    assert_eq!(all[1][3], DoubleInfiniteNumber::PosInfinity);
+    let expected: Vec<Vec<DoubleInfiniteNumber<i8>>> = floyd_warshall(&g);
+    assert_eq!(all, expected);
+}
+
+#[test]
+fn test_prim_matches_example_and_keeps_max_weight_edges() {
+    let mut g = AdjacencyList::new(4);
+    g.add_undirected_edge(0, 1, 2u32);
+    g.add_undirected_edge(1, 2, 3);
+    g.add_undirected_edge(0, 2, 1);
+    g.add_undirected_edge(1, 3, u32::MAX);
+    g.add_undirected_edge(2, 3, u32::MAX);
+
+    let (parent, key): (_, Vec<Dist>) = prim(&g);
+    assert_eq!(parent, vec![None, Some(0), Some(0), Some(2)]);
+    assert_eq!(key, vec![d(0), d(2), d(1), d(u32::MAX)]);
+}
+
+#[test]
+fn test_prim_spanning_forest() {
+    let mut g = AdjacencyList::new(4);
+    g.add_undirected_edge(0, 1, 5u32);
+    g.add_undirected_edge(2, 3, 7);
+    let (parent, _): (_, Vec<Dist>) = prim(&g);
+    assert_eq!(parent, vec![None, Some(0), None, Some(2)]);
+}
+
+#[test]
+fn test_kruskal() {
+    let mut g = AdjacencyList::new(5);
+    g.add_undirected_edge(0, 1, 2u32);
+    g.add_undirected_edge(0, 3, 6);
+    g.add_undirected_edge(1, 2, 3);
+    g.add_undirected_edge(1, 3, 8);
+    g.add_undirected_edge(1, 4, 5);
+    g.add_undirected_edge(2, 4, 7);
+    g.add_undirected_edge(3, 4, 9);
+
+    let mst = kruskal(&g);
+    assert_eq!(mst, vec![(0, 1, 2), (1, 2, 3), (1, 4, 5), (0, 3, 6)]);
+}
@@ -0,0 +1,70 @@
+use intfinity::{SingleInfiniteNumber, DoubleInfiniteNumber};
+use intfinity::traits::{Infinite, NegInfinite};
+
+fn shortest<N: Infinite>(candidates: &[N]) -> N {
+    candidates.iter().fold(N::infinity(), |acc, &c| acc.min(c))
+}
+
+fn path_cost<N: Infinite>(start: N::Value, edges: &[N]) -> Option<N> {
+    edges.iter().try_fold(N::finite(start), |acc, &e| acc.try_add(e))
+}
+
+#[test]
+fn test_infinity_and_finite_constructors() {
+    assert_eq!(<SingleInfiniteNumber<u32> as Infinite>::infinity(), SingleInfiniteNumber::Infinity);
+    assert_eq!(<SingleInfiniteNumber<u32> as Infinite>::finite(3), SingleInfiniteNumber::Finite(3));
+    assert_eq!(<DoubleInfiniteNumber<i64> as Infinite>::infinity(), DoubleInfiniteNumber::PosInfinity);
+    assert_eq!(<DoubleInfiniteNumber<i64> as Infinite>::finite(-3), DoubleInfiniteNumber::Finite(-3));
+    assert_eq!(<DoubleInfiniteNumber<i64> as NegInfinite>::neg_infinity(), DoubleInfiniteNumber::NegInfinity);
+}
+
+#[test]
+fn test_is_finite_and_as_finite() {
+    let a: SingleInfiniteNumber<u32> = SingleInfiniteNumber::new(7);
+    let b: SingleInfiniteNumber<u32> = SingleInfiniteNumber::Infinity;
+    assert!(a.is_finite());
+    assert!(b.is_infinite());
+    assert_eq!(a.as_finite(), Some(7));
+    assert_eq!(b.as_finite(), None);
+
+    let c: DoubleInfiniteNumber<i64> = DoubleInfiniteNumber::NegInfinity;
+    assert!(!c.is_finite());
+    assert_eq!(c.as_finite(), None);
+    assert_eq!(DoubleInfiniteNumber::new(-7i64).as_finite(), Some(-7));
+}
+
+#[test]
+fn test_try_add_double() {
+    let pos: DoubleInfiniteNumber<i32> = DoubleInfiniteNumber::PosInfinity;
+    let neg: DoubleInfiniteNumber<i32> = DoubleInfiniteNumber::NegInfinity;
+    assert_eq!(pos.try_add(neg), None);
+    assert_eq!(neg.try_add(pos), None);
+    assert_eq!(pos.try_add(DoubleInfiniteNumber::new(1)), Some(pos));
+    assert_eq!(DoubleInfiniteNumber::new(i32::MAX).try_add(DoubleInfiniteNumber::new(1)), Some(pos));
+    assert_eq!(DoubleInfiniteNumber::new(2).try_add(DoubleInfiniteNumber::new(3)), Some(DoubleInfiniteNumber::new(5)));
+}
+
+#[test]
+fn test_try_add_single() {
+    let inf: SingleInfiniteNumber<u8> = SingleInfiniteNumber::Infinity;
+    assert_eq!(inf.try_add(inf), Some(inf));
+    assert_eq!(SingleInfiniteNumber::new(250u8).try_add(SingleInfiniteNumber::new(10)), Some(inf));
+    assert_eq!(SingleInfiniteNumber::new(2u8).try_add(SingleInfiniteNumber::new(3)), Some(SingleInfiniteNumber::new(5)));
+}
+
+#[test]
+fn test_generic_min_over_both_types() {
+    let single = [SingleInfiniteNumber::new(5u32), SingleInfiniteNumber::Infinity, SingleInfiniteNumber::new(2)];
+    let double = [DoubleInfiniteNumber::new(5i64), DoubleInfiniteNumber::NegInfinity, DoubleInfiniteNumber::new(2)];
+    assert_eq!(shortest(&single), SingleInfiniteNumber::new(2));
+    assert_eq!(shortest(&double), DoubleInfiniteNumber::NegInfinity);
+    assert_eq!(shortest::<SingleInfiniteNumber<u32>>(&[]), SingleInfiniteNumber::Infinity);
+}
+
+#[test]
+fn test_generic_path_cost_over_both_types() {
+    let single = [SingleInfiniteNumber::new(5u32), SingleInfiniteNumber::new(2)];
+    let double = [DoubleInfiniteNumber::new(5i64), DoubleInfiniteNumber::PosInfinity, DoubleInfiniteNumber::NegInfinity];
+    assert_eq!(path_cost(0, &single), Some(SingleInfiniteNumber::new(7)));
+    assert_eq!(path_cost(0, &double), None);
+}
@@ -0,0 +1,137 @@
+use intfinity::DoubleInfiniteNumber;
+use intfinity::interval::{BoundType, Interval};
+
+const POS: DoubleInfiniteNumber<i32> = DoubleInfiniteNumber::PosInfinity;
+const NEG: DoubleInfiniteNumber<i32> = DoubleInfiniteNumber::NegInfinity;
+
+fn f(v: i32) -> DoubleInfiniteNumber<i32> {
+    DoubleInfiniteNumber::new(v)
+}
+
+fn closed(lo: i32, hi: i32) -> Interval<i32> {
+    Interval::closed(f(lo), f(hi))
+}
+
+#[test]
+fn test_infinite_endpoints_are_open() {
+    let i = Interval::closed(NEG, f(3));
+    assert_eq!(i.lo_type(), BoundType::Open);
+    assert_eq!(i.hi_type(), BoundType::Closed);
+    assert_eq!(i, Interval::new(NEG, BoundType::Open, f(3), BoundType::Closed));
+}
+
+#[test]
+fn test_is_empty() {
+    assert!(Interval::<i32>::empty().is_empty());
+    assert!(closed(3, 1).is_empty());
+    assert!(Interval::open(f(2), f(2)).is_empty());
+    assert!(Interval::new(f(2), BoundType::Closed, f(2), BoundType::Open).is_empty());
+    assert!(Interval::closed(POS, POS).is_empty());
+    assert!(!closed(2, 2).is_empty());
+    assert!(!Interval::<i32>::entire().is_empty());
+    assert_eq!(closed(3, 1), Interval::open(f(9), f(0)));
+}
+
+#[test]
+fn test_contains() {
+    let i = Interval::new(f(1), BoundType::Open, f(5), BoundType::Closed);
+    assert!(!i.contains(1));
+    assert!(i.contains(2));
+    assert!(i.contains(5));
+    assert!(!i.contains(6));
+    assert!(Interval::<i32>::entire().contains(i32::MIN));
+    assert!(Interval::open(f(0), POS).contains(i32::MAX));
+    assert!(!Interval::<i32>::empty().contains(0));
+}
+
+#[test]
+fn test_intersect() {
+    assert_eq!(closed(1, 5).intersect(&closed(3, 8)), closed(3, 5));
+    assert!(closed(1, 2).intersect(&closed(3, 8)).is_empty());
+    assert_eq!(
+        closed(1, 5).intersect(&Interval::open(f(1), POS)),
+        Interval::new(f(1), BoundType::Open, f(5), BoundType::Closed)
+    );
+    assert!(closed(1, 3).intersect(&Interval::open(f(3), f(4))).is_empty());
+    assert_eq!(Interval::entire().intersect(&closed(1, 2)), closed(1, 2));
+}
+
+#[test]
+fn test_hull() {
+    assert_eq!(closed(1, 2).hull(&closed(5, 8)), closed(1, 8));
+    assert_eq!(Interval::open(f(1), f(3)).hull(&closed(1, 2)), Interval::new(f(1), BoundType::Closed, f(3), BoundType::Open));
+    assert_eq!(Interval::empty().hull(&closed(1, 2)), closed(1, 2));
+    assert_eq!(closed(1, 2).hull(&Interval::empty()), closed(1, 2));
+    assert_eq!(closed(1, 2).hull(&Interval::closed(NEG, f(0))), Interval::closed(NEG, f(2)));
+}
+
+#[test]
+fn test_width() {
+    assert_eq!(closed(-2, 5).width(), f(7));
+    assert_eq!(Interval::closed(f(0), POS).width(), POS);
+    assert_eq!(Interval::<i32>::entire().width(), POS);
+    assert_eq!(Interval::<i32>::empty().width(), f(0));
+}
+
+#[test]
+fn test_add_and_sub() {
+    assert_eq!(closed(1, 2) + closed(10, 20), closed(11, 22));
+    assert_eq!(closed(1, 2) - closed(10, 20), closed(-19, -8));
+    assert_eq!(Interval::closed(NEG, f(2)) + closed(1, 1), Interval::closed(NEG, f(3)));
+    assert_eq!(Interval::closed(NEG, f(2)) - Interval::closed(NEG, f(0)), Interval::entire());
+    assert_eq!(
+        Interval::new(f(1), BoundType::Open, f(2), BoundType::Closed) + closed(0, 1),
+        Interval::new(f(1), BoundType::Open, f(3), BoundType::Closed)
+    );
+    assert!((Interval::empty() + closed(0, 1)).is_empty());
+}
+
+#[test]
+fn test_mul() {
+    assert_eq!(closed(-2, 3) * closed(4, 5), closed(-10, 15));
+    assert_eq!(closed(-2, -1) * closed(-3, 4), closed(-8, 6));
+    assert_eq!(closed(0, 0) * Interval::entire(), closed(0, 0));
+    assert_eq!(closed(0, 1) * Interval::closed(f(1), POS), Interval::closed(f(0), POS));
+    assert_eq!(closed(-1, 1) * Interval::closed(f(1), POS), Interval::entire());
+    assert_eq!(
+        Interval::new(f(0), BoundType::Open, f(2), BoundType::Closed) * closed(3, 3),
+        Interval::new(f(0), BoundType::Open, f(6), BoundType::Closed)
+    );
+    assert!((Interval::empty() * closed(0, 1)).is_empty());
+}
+
+#[test]
+fn test_div_without_zero() {
+    assert_eq!(closed(10, 20) / closed(2, 5), closed(2, 10));
+    assert_eq!(closed(-20, 10) / closed(-5, -2), closed(-5, 10));
+    assert_eq!(closed(1, 2) / Interval::closed(NEG, f(-1)), closed(-2, 0));
+    assert_eq!(Interval::closed(f(5), POS) / Interval::closed(f(5), POS), Interval::closed(f(0), POS));
+    assert_eq!(Interval::entire() / closed(1, 2), Interval::entire());
+}
+
+#[test]
+fn test_div_with_zero_in_divisor() {
+    assert!((closed(1, 2) / closed(0, 0)).is_empty());
+    assert_eq!(closed(0, 0) / closed(-1, 1), closed(0, 0));
+    assert_eq!(closed(1, 2) / closed(-1, 1), Interval::entire());
+    assert_eq!(closed(4, 8) / closed(0, 2), Interval::closed(f(2), POS));
+    assert_eq!(closed(-8, -4) / closed(0, 2), Interval::closed(NEG, f(-2)));
+    assert_eq!(closed(-8, 4) / closed(0, 2), Interval::entire());
+    assert_eq!(closed(4, 8) / closed(-2, 0), Interval::closed(NEG, f(-2)));
+    assert_eq!(closed(-8, -4) / closed(-2, 0), Interval::closed(f(2), POS));
+    assert_eq!(closed(4, 8) / Interval::new(f(0), BoundType::Open, f(2), BoundType::Closed), Interval::closed(f(2), POS));
+}
+
+#[test]
+fn test_overflow_gives_half_line() {
+    let above_max = Interval::new(f(i32::MAX), BoundType::Open, POS, BoundType::Open);
+    let below_min = Interval::new(NEG, BoundType::Open, f(i32::MIN), BoundType::Open);
+    assert_eq!(closed(i32::MAX, i32::MAX) + Interval::point(1), above_max);
+    assert_eq!(closed(i32::MAX - 1, i32::MAX) + closed(2, 3), above_max);
+    assert_eq!(closed(i32::MAX, i32::MAX) * closed(2, 2), above_max);
+    assert_eq!(closed(i32::MIN, i32::MIN) * closed(-2, -2), above_max);
+    assert_eq!(closed(i32::MIN, i32::MIN) - Interval::point(1), below_min);
+    assert_eq!(closed(i32::MIN, i32::MIN) * closed(2, 2), below_min);
+    assert_eq!(closed(i32::MIN, i32::MIN) / closed(-1, -1), above_max);
+    assert_eq!(closed(0, i32::MAX) + closed(1, 1), Interval::new(f(1), BoundType::Closed, POS, BoundType::Open));
+}
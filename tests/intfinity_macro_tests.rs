@@ -47,4 +47,42 @@ fn test_double_bounded_infinity_default_neg_infinity() {
 fn test_double_bounded_infinity_default_finite() {
     let finite = intfinity!(42);
     assert_eq!(finite, DoubleInfiniteNumber::Finite(42));
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_typed_forms() {
+    assert_eq!(intfinity!(5: i64), DoubleInfiniteNumber::<i64>::Finite(5));
+    assert_eq!(intfinity!(-5: i8), DoubleInfiniteNumber::<i8>::Finite(-5));
+    assert_eq!(intfinity!(inf: i16), DoubleInfiniteNumber::<i16>::PosInfinity);
+    assert_eq!(intfinity!(-inf: i16, double_bound), DoubleInfiniteNumber::<i16>::NegInfinity);
+    assert_eq!(intfinity!(inf: u32, single_bound), SingleInfiniteNumber::<u32>::Infinity);
+    assert_eq!(intfinity!(7: u8, single_bound), SingleInfiniteNumber::<u8>::Finite(7));
+
+    let budget = 12;
+    assert_eq!(intfinity!(budget: i32), DoubleInfiniteNumber::Finite(12));
+}
+
+#[test]
+fn test_list_form() {
+    let values: [DoubleInfiniteNumber<i32>; 5] = intfinity![1, inf, -inf, 2 + 3, -4];
+    assert_eq!(
+        values,
+        [
+            DoubleInfiniteNumber::Finite(1),
+            DoubleInfiniteNumber::PosInfinity,
+            DoubleInfiniteNumber::NegInfinity,
+            DoubleInfiniteNumber::Finite(5),
+            DoubleInfiniteNumber::Finite(-4),
+        ]
+    );
+    assert_eq!(intfinity![inf, 0,].to_vec(), vec![DoubleInfiniteNumber::<i32>::PosInfinity, DoubleInfiniteNumber::Finite(0)]);
+}
+
+mod without_imports {
+    #[test]
+    fn test_macro_uses_crate_paths() {
+        let x: intfinity::SingleInfiniteNumber<u32> = intfinity::intfinity!(inf, single_bound);
+        assert_eq!(x, intfinity::SingleInfiniteNumber::Infinity);
+        assert_eq!(intfinity::intfinity!(3: i32), intfinity::DoubleInfiniteNumber::Finite(3));
+    }
+}
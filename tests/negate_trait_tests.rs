@@ -41,3 +41,13 @@ fn test_negate_large_value() {
     let result = a.negate_double_bounded_infinity();
     assert_eq!(result, DoubleInfiniteNumber::Finite(-100000));
 }
+
+#[test]
+fn test_negate_min_promotes_to_infinity() {
+    let a = DoubleInfiniteNumber::Finite(i32::MIN);
+    let result = a.negate_double_bounded_infinity();
+    assert_eq!(result, DoubleInfiniteNumber::PosInfinity);
+
+    let b = DoubleInfiniteNumber::Finite(i32::MIN + 1);
+    assert_eq!(b.negate_double_bounded_infinity(), DoubleInfiniteNumber::Finite(i32::MAX));
+}
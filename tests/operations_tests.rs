@@ -120,6 +120,14 @@ fn test_subtraction_with_overflow() {
     assert_eq!(result, DoubleInfiniteNumber::PosInfinity);
 }
 
+#[test]
+fn test_subtraction_zero_minus_min_overflows_positively() {
+    let a = DoubleInfiniteNumber::new(0);
+    let b = DoubleInfiniteNumber::new(i32::MIN);
+    let result = a - b;
+    assert_eq!(result, DoubleInfiniteNumber::PosInfinity);
+}
+
 #[test]
 fn test_multiplication_finite_values() {
     let a = DoubleInfiniteNumber::new(4);
@@ -0,0 +1,66 @@
+use std::cmp::Ordering;
+
+use intfinity::{SingleInfiniteNumber, DoubleInfiniteNumber};
+use intfinity::ordering::{argmax, argmin, sort_by_indeterminate, CmpPolicy, IndeterminateOrd};
+
+const POS: DoubleInfiniteNumber<i32> = DoubleInfiniteNumber::PosInfinity;
+const NEG: DoubleInfiniteNumber<i32> = DoubleInfiniteNumber::NegInfinity;
+
+fn f(v: i32) -> DoubleInfiniteNumber<i32> {
+    DoubleInfiniteNumber::new(v)
+}
+
+#[test]
+fn test_cmp_with() {
+    assert_eq!(POS.cmp_with(&POS, CmpPolicy::Total), Some(Ordering::Equal));
+    assert_eq!(POS.cmp_with(&POS, CmpPolicy::Indeterminate), None);
+    assert_eq!(NEG.cmp_with(&f(0), CmpPolicy::Indeterminate), Some(Ordering::Less));
+    assert_eq!(f(1).cmp_with(&f(1), CmpPolicy::Indeterminate), Some(Ordering::Equal));
+
+    let inf = SingleInfiniteNumber::<u8>::Infinity;
+    assert_eq!(inf.cmp_with(&inf, CmpPolicy::Total), Some(Ordering::Equal));
+    assert_eq!(inf.cmp_with(&inf, CmpPolicy::Indeterminate), None);
+}
+
+#[test]
+fn test_indeterminate_min_max() {
+    assert_eq!(f(3).indeterminate_min(POS), Some(f(3)));
+    assert_eq!(NEG.indeterminate_max(f(3)), Some(f(3)));
+    assert_eq!(POS.indeterminate_min(POS), None);
+    assert_eq!(NEG.indeterminate_max(NEG), None);
+    assert_eq!(f(2).indeterminate_max(f(2)), Some(f(2)));
+    assert_eq!(SingleInfiniteNumber::new(4u32).indeterminate_max(SingleInfiniteNumber::Infinity), Some(SingleInfiniteNumber::Infinity));
+}
+
+#[test]
+fn test_indeterminate_clamp() {
+    assert_eq!(f(5).indeterminate_clamp(NEG, POS), Some(f(5)));
+    assert_eq!(POS.indeterminate_clamp(f(0), f(10)), Some(f(10)));
+    assert_eq!(NEG.indeterminate_clamp(f(0), POS), Some(f(0)));
+    assert_eq!(POS.indeterminate_clamp(f(0), POS), None);
+    assert_eq!(POS.clamp(f(0), POS), POS);
+}
+
+#[test]
+#[should_panic(expected = "clamp lower bound is greater than upper bound")]
+fn test_clamp_with_inverted_bounds() {
+    let _ = f(0).indeterminate_clamp(POS, NEG);
+}
+
+#[test]
+fn test_argmin_argmax_first_occurrence() {
+    let values = [POS, f(2), NEG, f(-1), NEG, POS];
+    assert_eq!(argmin(&values), Some(2));
+    assert_eq!(argmax(&values), Some(0));
+    assert_eq!(argmin::<DoubleInfiniteNumber<i32>>(&[]), None);
+
+    let values = [SingleInfiniteNumber::new(3u32), SingleInfiniteNumber::new(1), SingleInfiniteNumber::new(1)];
+    assert_eq!(argmin(&values), Some(1));
+}
+
+#[test]
+fn test_sort_by_indeterminate() {
+    let mut values = [POS, f(2), NEG, f(-1), POS, NEG];
+    sort_by_indeterminate(&mut values);
+    assert_eq!(values, [NEG, NEG, f(-1), f(2), POS, POS]);
+}
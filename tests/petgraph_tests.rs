@@ -0,0 +1,118 @@
+use intfinity::{SingleInfiniteNumber, DoubleInfiniteNumber};
+use petgraph::algo::{bellman_ford, dijkstra, floyd_warshall, BoundedMeasure, FloatMeasure, Measure};
+use petgraph::graph::{DiGraph, NodeIndex};
+
+type Dist = SingleInfiniteNumber<u32>;
+type SignedDist = DoubleInfiniteNumber<i64>;
+
+fn assert_measure<M: Measure>() {}
+
+#[test]
+fn test_infinite_numbers_are_measures() {
+    assert_measure::<Dist>();
+    assert_measure::<SignedDist>();
+    assert_eq!(Dist::default(), SingleInfiniteNumber::Finite(0));
+    assert_eq!(SignedDist::default(), DoubleInfiniteNumber::Finite(0));
+}
+
+#[test]
+fn test_dijkstra_with_single_infinite_weights() {
+    let mut g = DiGraph::new();
+    let a = g.add_node(());
+    let b = g.add_node(());
+    let c = g.add_node(());
+    let d = g.add_node(());
+    g.add_edge(a, b, SingleInfiniteNumber::new(4u32));
+    g.add_edge(a, c, SingleInfiniteNumber::new(1));
+    g.add_edge(c, b, SingleInfiniteNumber::new(2));
+    g.add_edge(b, d, SingleInfiniteNumber::Infinity);
+
+    let dist = dijkstra(&g, a, None, |e| *e.weight());
+    assert_eq!(dist[&a], SingleInfiniteNumber::new(0));
+    assert_eq!(dist[&b], SingleInfiniteNumber::new(3));
+    assert_eq!(dist[&c], SingleInfiniteNumber::new(1));
+    assert_eq!(dist[&d], SingleInfiniteNumber::Infinity);
+}
+
+#[test]
+fn test_bellman_ford_with_single_infinite_weights() {
+    let mut g = DiGraph::new();
+    let nodes: Vec<NodeIndex> = (0..4).map(|_| g.add_node(())).collect();
+    g.add_edge(nodes[0], nodes[1], SingleInfiniteNumber::new(4u32));
+    g.add_edge(nodes[0], nodes[2], SingleInfiniteNumber::new(5));
+    g.add_edge(nodes[1], nodes[2], SingleInfiniteNumber::new(0));
+    g.add_edge(nodes[3], nodes[0], SingleInfiniteNumber::Infinity);
+
+    let paths = bellman_ford(&g, nodes[0]).unwrap();
+    assert_eq!(paths.distances, vec![
+        SingleInfiniteNumber::new(0),
+        SingleInfiniteNumber::new(4),
+        SingleInfiniteNumber::new(4),
+        SingleInfiniteNumber::Infinity,
+    ]);
+}
+
+#[test]
+fn test_floyd_warshall_with_infinite_weights() {
+    let mut g = DiGraph::new();
+    let a = g.add_node(());
+    let b = g.add_node(());
+    let c = g.add_node(());
+    g.add_edge(a, b, DoubleInfiniteNumber::new(2i64));
+    g.add_edge(b, c, DoubleInfiniteNumber::new(-1));
+    g.add_edge(a, c, DoubleInfiniteNumber::new(5));
+
+    let dist = floyd_warshall(&g, |e| *e.weight()).unwrap();
+    assert_eq!(dist[&(a, c)], DoubleInfiniteNumber::new(1));
+    assert_eq!(dist[&(c, a)], DoubleInfiniteNumber::PosInfinity);
+    assert_eq!(dist[&(b, b)], DoubleInfiniteNumber::new(0));
+}
+
+#[test]
+fn test_floyd_warshall_ignores_overflow() {
+    let mut g = DiGraph::new();
+    let a = g.add_node(());
+    let b = g.add_node(());
+    let c = g.add_node(());
+    g.add_edge(a, b, SingleInfiniteNumber::new(u32::MAX));
+    g.add_edge(b, c, SingleInfiniteNumber::new(1u32));
+
+    let dist = floyd_warshall(&g, |e| *e.weight()).unwrap();
+    assert_eq!(dist[&(a, b)], SingleInfiniteNumber::new(u32::MAX));
+    assert_eq!(dist[&(a, c)], SingleInfiniteNumber::Infinity);
+}
+
+#[test]
+fn test_bounded_measure() {
+    assert_eq!(<SignedDist as BoundedMeasure>::min(), DoubleInfiniteNumber::NegInfinity);
+    assert_eq!(<SignedDist as BoundedMeasure>::max(), DoubleInfiniteNumber::PosInfinity);
+    assert_eq!(<Dist as BoundedMeasure>::min(), SingleInfiniteNumber::Finite(0));
+    assert_eq!(<Dist as BoundedMeasure>::max(), SingleInfiniteNumber::Infinity);
+
+    let big = DoubleInfiniteNumber::new(i64::MAX);
+    // the inherent `overflowing_add` takes precedence in method syntax
+    assert_eq!(BoundedMeasure::overflowing_add(big, DoubleInfiniteNumber::new(1)), (DoubleInfiniteNumber::PosInfinity, true));
+    assert_eq!(BoundedMeasure::overflowing_add(DoubleInfiniteNumber::PosInfinity, DoubleInfiniteNumber::new(1i64)), (DoubleInfiniteNumber::PosInfinity, false));
+    assert!(BoundedMeasure::overflowing_add(DoubleInfiniteNumber::<i64>::PosInfinity, DoubleInfiniteNumber::NegInfinity).1);
+    assert_eq!(BoundedMeasure::overflowing_add(SingleInfiniteNumber::new(u32::MAX), SingleInfiniteNumber::new(1)), (SingleInfiniteNumber::Infinity, true));
+}
+
+#[test]
+fn test_from_float_conversions() {
+    assert_eq!(<SignedDist as BoundedMeasure>::from_f64(-3.7), DoubleInfiniteNumber::new(-3));
+    assert_eq!(<SignedDist as BoundedMeasure>::from_f64(f64::INFINITY), DoubleInfiniteNumber::PosInfinity);
+    assert_eq!(<SignedDist as BoundedMeasure>::from_f64(-1e300), DoubleInfiniteNumber::NegInfinity);
+    assert_eq!(<DoubleInfiniteNumber<i8> as BoundedMeasure>::from_f32(127.0), DoubleInfiniteNumber::new(127));
+    assert_eq!(<DoubleInfiniteNumber<i8> as BoundedMeasure>::from_f32(128.0), DoubleInfiniteNumber::PosInfinity);
+    assert_eq!(<DoubleInfiniteNumber<i8> as BoundedMeasure>::from_f32(-128.0), DoubleInfiniteNumber::new(-128));
+    assert_eq!(<SingleInfiniteNumber<u8> as FloatMeasure>::from_f64(255.9), SingleInfiniteNumber::new(255));
+    assert_eq!(<SingleInfiniteNumber<u8> as FloatMeasure>::from_f64(256.0), SingleInfiniteNumber::Infinity);
+    assert_eq!(<Dist as FloatMeasure>::infinite(), SingleInfiniteNumber::Infinity);
+    assert_eq!(<Dist as FloatMeasure>::zero(), SingleInfiniteNumber::Finite(0));
+}
+
+#[test]
+#[should_panic(expected = "cannot convert a negative value to a single-bounded infinite number")]
+fn test_from_float_negative_single() {
+    let _ = <Dist as FloatMeasure>::from_f64(-1.0);
+}
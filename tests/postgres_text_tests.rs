@@ -0,0 +1,74 @@
+use intfinity::DoubleInfiniteNumber;
+use intfinity::interval::{BoundType, Interval};
+use intfinity::postgres_text::{ParseError, PgRange, PgValue};
+
+fn value(s: &str) -> Result<DoubleInfiniteNumber<i32>, ParseError> {
+    s.parse::<PgValue<i32>>().map(|value| value.0)
+}
+
+fn range(s: &str) -> Result<Interval<i32>, ParseError> {
+    s.parse::<PgRange<i32>>().map(|range| range.0)
+}
+
+#[test]
+fn test_value_round_trip() {
+    for x in [DoubleInfiniteNumber::NegInfinity, DoubleInfiniteNumber::new(-42), DoubleInfiniteNumber::new(0), DoubleInfiniteNumber::PosInfinity] {
+        assert_eq!(value(&PgValue(x).to_string()), Ok(x));
+    }
+    assert_eq!(PgValue(DoubleInfiniteNumber::<i32>::PosInfinity).to_string(), "infinity");
+    assert_eq!(PgValue(DoubleInfiniteNumber::<i32>::NegInfinity).to_string(), "-infinity");
+}
+
+#[test]
+fn test_value_accepts_postgres_spellings() {
+    // numeric input accepts these, ignoring case
+    for s in ["infinity", "Infinity", "+infinity", "inf", "+Inf", " infinity "] {
+        assert_eq!(value(s), Ok(DoubleInfiniteNumber::PosInfinity));
+    }
+    for s in ["-infinity", "-Infinity", "-inf", "-INF"] {
+        assert_eq!(value(s), Ok(DoubleInfiniteNumber::NegInfinity));
+    }
+    assert_eq!(value(" 17 "), Ok(DoubleInfiniteNumber::new(17)));
+    assert_eq!(value("infinit"), Err(ParseError::InvalidValue));
+    assert_eq!(value(""), Err(ParseError::InvalidValue));
+}
+
+// examples from the "Range Types" chapter of the Postgres documentation
+#[test]
+fn test_documented_range_examples() {
+    assert_eq!(range("[3,7)"), Ok(Interval::new(DoubleInfiniteNumber::new(3), BoundType::Closed, DoubleInfiniteNumber::new(7), BoundType::Open)));
+    assert_eq!(range("(3,7)"), Ok(Interval::open(DoubleInfiniteNumber::new(3), DoubleInfiniteNumber::new(7))));
+    assert_eq!(range("[4,4]"), Ok(Interval::point(4)));
+    assert_eq!(range("[4,4)"), Ok(Interval::empty()));
+    assert_eq!(range("empty"), Ok(Interval::empty()));
+    assert_eq!(range("(,5]"), Ok(Interval::new(DoubleInfiniteNumber::NegInfinity, BoundType::Open, DoubleInfiniteNumber::new(5), BoundType::Closed)));
+    assert_eq!(range("[1,)"), Ok(Interval::new(DoubleInfiniteNumber::new(1), BoundType::Closed, DoubleInfiniteNumber::PosInfinity, BoundType::Open)));
+    assert_eq!(range("(,)"), Ok(Interval::entire()));
+}
+
+#[test]
+fn test_range_formatting() {
+    assert_eq!(PgRange(Interval::<i32>::empty()).to_string(), "empty");
+    assert_eq!(PgRange(Interval::<i32>::entire()).to_string(), "(,)");
+    assert_eq!(PgRange(Interval::point(4)).to_string(), "[4,4]");
+    let unbounded_below = Interval::new(DoubleInfiniteNumber::NegInfinity, BoundType::Closed, DoubleInfiniteNumber::new(10), BoundType::Closed);
+    assert_eq!(PgRange(unbounded_below).to_string(), "(,10]");
+    for s in ["[1,)", "(,10]", "[-3,7)", "(3,7)", "empty"] {
+        assert_eq!(PgRange(range(s).unwrap()).to_string(), s);
+    }
+}
+
+#[test]
+fn test_range_infinite_bounds_read_as_unbounded() {
+    assert_eq!(range("[-infinity,infinity]"), Ok(Interval::entire()));
+    assert_eq!(range("[ EMPTY ]"), Err(ParseError::InvalidRange));
+    assert_eq!(range("  Empty "), Ok(Interval::empty()));
+}
+
+#[test]
+fn test_malformed_ranges() {
+    for s in ["", "[", "]", "1,5", "[1,5", "1,5]", "[1 5]", "[1,2,3]"] {
+        assert_eq!(range(s), Err(ParseError::InvalidRange), "{:?}", s);
+    }
+    assert_eq!(range("[a,5]"), Err(ParseError::InvalidValue));
+}
@@ -0,0 +1,199 @@
+use std::cmp::Ordering;
+
+use intfinity::{SingleInfiniteNumber, DoubleInfiniteNumber};
+use proptest::prelude::*;
+
+// `+inf + (-inf)`, `inf - inf` and `0 * inf` panic, so the laws only cover
+// pairs where the operation is defined
+fn double_add_defined<T>(a: DoubleInfiniteNumber<T>, b: DoubleInfiniteNumber<T>) -> bool {
+    !matches!(
+        (a, b),
+        (DoubleInfiniteNumber::PosInfinity, DoubleInfiniteNumber::NegInfinity) | (DoubleInfiniteNumber::NegInfinity, DoubleInfiniteNumber::PosInfinity)
+    )
+}
+
+fn double_mul_defined<T: PartialEq + Default>(a: DoubleInfiniteNumber<T>, b: DoubleInfiniteNumber<T>) -> bool {
+    match (a, b) {
+        (DoubleInfiniteNumber::Finite(_), DoubleInfiniteNumber::Finite(_)) => true,
+        (DoubleInfiniteNumber::Finite(x), _) | (_, DoubleInfiniteNumber::Finite(x)) => x != T::default(),
+        _ => true,
+    }
+}
+
+fn single_mul_defined<T: PartialEq + Default + intfinity::traits::Unsigned>(a: SingleInfiniteNumber<T>, b: SingleInfiniteNumber<T>) -> bool {
+    match (a, b) {
+        (SingleInfiniteNumber::Finite(_), SingleInfiniteNumber::Finite(_)) => true,
+        (SingleInfiniteNumber::Finite(x), _) | (_, SingleInfiniteNumber::Finite(x)) => x != T::default(),
+        _ => true,
+    }
+}
+
+// the infinity an overflowing result must be promoted to, by its exact sign
+fn promoted<T, W: PartialOrd + Default>(exact: W) -> DoubleInfiniteNumber<T> {
+    if exact > W::default() {
+        DoubleInfiniteNumber::PosInfinity
+    } else {
+        DoubleInfiniteNumber::NegInfinity
+    }
+}
+
+// `$wide` is the oracle: i128 for the narrower types, f64 for i128, where only
+// the sign of an overflowing result matters
+macro_rules! double_laws {
+    ($name:ident, $t:ty, $wide:ty) => {
+        mod $name {
+            use super::*;
+
+            type D = DoubleInfiniteNumber<$t>;
+
+            fn finite() -> impl Strategy<Value = $t> {
+                prop_oneof![
+                    3 => any::<$t>(),
+                    1 => prop::sample::select(vec![<$t>::MIN, <$t>::MAX, 0, 1, -1]),
+                ]
+            }
+
+            fn number() -> impl Strategy<Value = D> {
+                prop_oneof![
+                    8 => finite().prop_map(DoubleInfiniteNumber::Finite),
+                    1 => Just(DoubleInfiniteNumber::NegInfinity),
+                    1 => Just(DoubleInfiniteNumber::PosInfinity),
+                ]
+            }
+
+            proptest! {
+                #[test]
+                fn add_commutes(a in number(), b in number()) {
+                    prop_assume!(double_add_defined(a, b));
+                    prop_assert_eq!(a + b, b + a);
+                }
+
+                #[test]
+                fn mul_commutes(a in number(), b in number()) {
+                    prop_assume!(double_mul_defined(a, b));
+                    prop_assert_eq!(a * b, b * a);
+                }
+
+                #[test]
+                fn sub_is_add_of_negation(a in number(), b in number()) {
+                    // -MIN is promoted to +inf, so the identity does not hold for it
+                    prop_assume!(b != DoubleInfiniteNumber::Finite(<$t>::MIN));
+                    let neg_b = b.negate_double_bounded_infinity();
+                    prop_assume!(double_add_defined(a, neg_b));
+                    prop_assert_eq!(a - b, a + neg_b);
+                }
+
+                #[test]
+                fn add_is_monotone(a in number(), b in number(), c in number()) {
+                    prop_assume!(double_add_defined(a, c) && double_add_defined(b, c));
+                    let (lo, hi) = if a <= b { (a, b) } else { (b, a) };
+                    prop_assert!(lo + c <= hi + c);
+                }
+
+                #[test]
+                fn ord_agrees_with_indeterminate_cmp(a in number(), b in number()) {
+                    match a.indeterminate_cmp(&b) {
+                        Some(ordering) => prop_assert_eq!(ordering, a.cmp(&b)),
+                        None => {
+                            prop_assert_eq!(a.cmp(&b), Ordering::Equal);
+                            prop_assert!(a == DoubleInfiniteNumber::PosInfinity || a == DoubleInfiniteNumber::NegInfinity);
+                        },
+                    }
+                }
+
+                #[test]
+                fn overflow_takes_the_exact_sign(a in finite(), b in finite()) {
+                    let (x, y) = (D::new(a), D::new(b));
+                    let (wa, wb) = (a as $wide, b as $wide);
+
+                    let sum = a.checked_add(b).map_or_else(|| promoted(wa + wb), DoubleInfiniteNumber::Finite);
+                    prop_assert_eq!(x + y, sum);
+                    let difference = a.checked_sub(b).map_or_else(|| promoted(wa - wb), DoubleInfiniteNumber::Finite);
+                    prop_assert_eq!(x - y, difference);
+                    let product = a.checked_mul(b).map_or_else(|| promoted(wa * wb), DoubleInfiniteNumber::Finite);
+                    prop_assert_eq!(x * y, product);
+                    if b != 0 {
+                        let quotient = a.checked_div(b).map_or_else(|| promoted(wa / wb), DoubleInfiniteNumber::Finite);
+                        prop_assert_eq!(x / y, quotient);
+                    }
+                }
+            }
+        }
+    };
+}
+
+macro_rules! single_laws {
+    ($name:ident, $t:ty) => {
+        mod $name {
+            use super::*;
+
+            type S = SingleInfiniteNumber<$t>;
+
+            fn finite() -> impl Strategy<Value = $t> {
+                prop_oneof![
+                    3 => any::<$t>(),
+                    1 => prop::sample::select(vec![<$t>::MAX, 0, 1]),
+                ]
+            }
+
+            fn number() -> impl Strategy<Value = S> {
+                prop_oneof![
+                    9 => finite().prop_map(SingleInfiniteNumber::Finite),
+                    1 => Just(SingleInfiniteNumber::Infinity),
+                ]
+            }
+
+            proptest! {
+                #[test]
+                fn add_commutes(a in number(), b in number()) {
+                    prop_assert_eq!(a + b, b + a);
+                }
+
+                #[test]
+                fn mul_commutes(a in number(), b in number()) {
+                    prop_assume!(single_mul_defined(a, b));
+                    prop_assert_eq!(a * b, b * a);
+                }
+
+                #[test]
+                fn add_is_monotone(a in number(), b in number(), c in number()) {
+                    let (lo, hi) = if a <= b { (a, b) } else { (b, a) };
+                    prop_assert!(lo + c <= hi + c);
+                }
+
+                #[test]
+                fn ord_agrees_with_indeterminate_cmp(a in number(), b in number()) {
+                    match a.indeterminate_cmp(&b) {
+                        Some(ordering) => prop_assert_eq!(ordering, a.cmp(&b)),
+                        None => prop_assert!(a == SingleInfiniteNumber::Infinity && b == SingleInfiniteNumber::Infinity),
+                    }
+                }
+
+                #[test]
+                fn overflow_matches_the_oracle(a in finite(), b in finite()) {
+                    let (x, y) = (S::new(a), S::new(b));
+                    // u128 is the oracle; it only overflows itself for u128 operands
+                    let (wa, wb) = (u128::from(a), u128::from(b));
+                    let fits = |exact: Option<u128>| {
+                        exact.and_then(|e| <$t>::try_from(e).ok()).map_or(SingleInfiniteNumber::Infinity, SingleInfiniteNumber::Finite)
+                    };
+                    prop_assert_eq!(x + y, fits(wa.checked_add(wb)));
+                    prop_assert_eq!(x * y, fits(wa.checked_mul(wb)));
+                    prop_assert_eq!(x - y, S::new(a.saturating_sub(b)));
+                }
+            }
+        }
+    };
+}
+
+double_laws!(double_i8, i8, i128);
+double_laws!(double_i16, i16, i128);
+double_laws!(double_i32, i32, i128);
+double_laws!(double_i64, i64, i128);
+double_laws!(double_i128, i128, f64);
+
+single_laws!(single_u8, u8);
+single_laws!(single_u16, u16);
+single_laws!(single_u32, u32);
+single_laws!(single_u64, u64);
+single_laws!(single_u128, u128);
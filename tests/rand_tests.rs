@@ -0,0 +1,78 @@
+use intfinity::{SingleInfiniteNumber, DoubleInfiniteNumber};
+use intfinity::rand_impls::WithInfinities;
+use rand::distr::uniform::{Error, UniformSampler};
+use rand::distr::{Distribution, Uniform};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+fn rng() -> ChaCha8Rng {
+    ChaCha8Rng::seed_from_u64(42)
+}
+
+#[test]
+fn test_seeded_generation_is_reproducible() {
+    let dist = WithInfinities::new(Uniform::new(0u32, 100).unwrap(), 0.2);
+    let a: Vec<SingleInfiniteNumber<u32>> = dist.sample_iter(rng()).take(64).collect();
+    let b: Vec<SingleInfiniteNumber<u32>> = dist.sample_iter(rng()).take(64).collect();
+    assert_eq!(a, b);
+}
+
+#[test]
+fn test_infinity_frequencies() {
+    let dist = WithInfinities::new(Uniform::new(-10i64, 10).unwrap(), 0.25).with_neg_infinity(0.1);
+    let samples: Vec<DoubleInfiniteNumber<i64>> = dist.sample_iter(rng()).take(10_000).collect();
+    let pos = samples.iter().filter(|x| **x == DoubleInfiniteNumber::PosInfinity).count();
+    let neg = samples.iter().filter(|x| **x == DoubleInfiniteNumber::NegInfinity).count();
+    assert!((2_300..2_700).contains(&pos));
+    assert!((800..1_200).contains(&neg));
+    assert!(samples.iter().all(|x| match x {
+        DoubleInfiniteNumber::Finite(v) => (-10..10).contains(v),
+        _ => true,
+    }));
+}
+
+#[test]
+fn test_zero_probability_is_always_finite() {
+    let dist = WithInfinities::new(Uniform::new(0u8, 3).unwrap(), 0.0);
+    let mut rng = rng();
+    for _ in 0..1_000 {
+        let x: SingleInfiniteNumber<u8> = dist.sample(&mut rng);
+        assert!(matches!(x, SingleInfiniteNumber::Finite(0..=2)));
+    }
+}
+
+#[test]
+#[should_panic(expected = "infinity probabilities must lie in [0, 1] and sum to at most 1")]
+fn test_rejects_bad_probabilities() {
+    let _ = WithInfinities::new(Uniform::new(0u8, 3).unwrap(), 0.7).with_neg_infinity(0.5);
+}
+
+#[test]
+fn test_pos_infinity_only_samples_either_enum() {
+    let dist = WithInfinities::new(Uniform::new(-3i8, 3).unwrap(), 0.5);
+    let samples: Vec<DoubleInfiniteNumber<i8>> = dist.sample_iter(rng()).take(1_000).collect();
+    assert!(!samples.contains(&DoubleInfiniteNumber::NegInfinity));
+    assert!(samples.contains(&DoubleInfiniteNumber::PosInfinity));
+}
+
+#[test]
+fn test_uniform_over_finite_bounds() {
+    let mut rng = rng();
+    for _ in 0..1_000 {
+        let x = rng.random_range(DoubleInfiniteNumber::new(-5i32)..=DoubleInfiniteNumber::new(5));
+        assert!(matches!(x, DoubleInfiniteNumber::Finite(-5..=5)));
+        let y = rng.random_range(SingleInfiniteNumber::new(10u16)..SingleInfiniteNumber::new(20));
+        assert!(matches!(y, SingleInfiniteNumber::Finite(10..=19)));
+    }
+}
+
+#[test]
+fn test_uniform_rejects_infinite_bounds() {
+    let sampler = <DoubleInfiniteNumber<i32> as rand::distr::uniform::SampleUniform>::Sampler::new(
+        DoubleInfiniteNumber::new(0),
+        DoubleInfiniteNumber::PosInfinity,
+    );
+    assert_eq!(sampler.err(), Some(Error::NonFinite));
+    assert!(Uniform::new(SingleInfiniteNumber::new(1u32), SingleInfiniteNumber::Infinity).is_err());
+    assert_eq!(Uniform::new(DoubleInfiniteNumber::new(3), DoubleInfiniteNumber::new(3)).err(), Some(Error::EmptyRange));
+}
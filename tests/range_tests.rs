@@ -0,0 +1,76 @@
+use std::collections::BTreeMap;
+use std::ops::{Bound, RangeBounds};
+
+use intfinity::{SingleInfiniteNumber, DoubleInfiniteNumber};
+use intfinity::range::InfRange;
+
+fn sample_map() -> BTreeMap<i32, char> {
+    [(-5, 'a'), (0, 'b'), (3, 'c'), (7, 'd')].into_iter().collect()
+}
+
+#[test]
+fn test_double_into_bound() {
+    assert_eq!(Bound::from(DoubleInfiniteNumber::new(3)), Bound::Included(3));
+    assert_eq!(Bound::<i32>::from(DoubleInfiniteNumber::PosInfinity), Bound::Unbounded);
+    assert_eq!(Bound::<i32>::from(DoubleInfiniteNumber::NegInfinity), Bound::Unbounded);
+}
+
+#[test]
+fn test_single_into_bound() {
+    assert_eq!(Bound::from(SingleInfiniteNumber::new(3u32)), Bound::Included(3));
+    assert_eq!(Bound::<u32>::from(SingleInfiniteNumber::Infinity), Bound::Unbounded);
+}
+
+#[test]
+fn test_unbounded_back_to_matching_infinity() {
+    assert_eq!(DoubleInfiniteNumber::<i32>::from_lower_bound(Bound::Unbounded), DoubleInfiniteNumber::NegInfinity);
+    assert_eq!(DoubleInfiniteNumber::<i32>::from_upper_bound(Bound::Unbounded), DoubleInfiniteNumber::PosInfinity);
+    assert_eq!(DoubleInfiniteNumber::from_lower_bound(Bound::Excluded(4)), DoubleInfiniteNumber::new(4));
+    assert_eq!(SingleInfiniteNumber::<u32>::from_upper_bound(Bound::Unbounded), SingleInfiniteNumber::Infinity);
+    assert_eq!(SingleInfiniteNumber::<u32>::from_lower_bound(Bound::Unbounded), SingleInfiniteNumber::new(0));
+    assert_eq!(SingleInfiniteNumber::from_upper_bound(Bound::Included(9u8)), SingleInfiniteNumber::new(9));
+}
+
+#[test]
+fn test_btreemap_range_with_infinite_bounds() {
+    let map = sample_map();
+    let all: Vec<_> = map.range(InfRange::new(DoubleInfiniteNumber::NegInfinity, DoubleInfiniteNumber::PosInfinity)).collect();
+    assert_eq!(all.len(), 4);
+
+    let upper: Vec<_> = map.range(InfRange::new(DoubleInfiniteNumber::new(0), DoubleInfiniteNumber::PosInfinity)).map(|(k, _)| *k).collect();
+    assert_eq!(upper, vec![0, 3, 7]);
+
+    let lower: Vec<_> = map.range(InfRange::new(DoubleInfiniteNumber::NegInfinity, DoubleInfiniteNumber::new(3))).map(|(k, _)| *k).collect();
+    assert_eq!(lower, vec![-5, 0, 3]);
+
+    let half_open: Vec<_> = map.range(InfRange::half_open(DoubleInfiniteNumber::new(-5), DoubleInfiniteNumber::new(3))).map(|(k, _)| *k).collect();
+    assert_eq!(half_open, vec![-5, 0]);
+}
+
+#[test]
+fn test_single_range() {
+    let map: BTreeMap<u32, ()> = [1, 4, 9].into_iter().map(|k| (k, ())).collect();
+    let keys: Vec<_> = map.range(InfRange::from_single(SingleInfiniteNumber::new(2), SingleInfiniteNumber::Infinity)).map(|(k, _)| *k).collect();
+    assert_eq!(keys, vec![4, 9]);
+}
+
+#[test]
+fn test_range_bounds() {
+    let r = InfRange::half_open(DoubleInfiniteNumber::NegInfinity, DoubleInfiniteNumber::new(2));
+    assert_eq!(r.start_bound(), Bound::Unbounded);
+    assert_eq!(r.end_bound(), Bound::Excluded(&2));
+    assert!(r.contains(&-100));
+    assert!(!r.contains(&2));
+}
+
+#[test]
+#[should_panic(expected = "range start is +infinity")]
+fn test_range_starting_at_pos_infinity() {
+    let _ = InfRange::<i32>::new(DoubleInfiniteNumber::PosInfinity, DoubleInfiniteNumber::PosInfinity);
+}
+
+#[test]
+#[should_panic(expected = "range end is -infinity")]
+fn test_range_ending_at_neg_infinity() {
+    let _ = InfRange::<i32>::new(DoubleInfiniteNumber::new(0), DoubleInfiniteNumber::NegInfinity);
+}
@@ -0,0 +1,78 @@
+use intfinity::{SingleInfiniteNumber, DoubleInfiniteNumber};
+use schemars::{schema_for, JsonSchema, SchemaGenerator};
+use serde_json::json;
+
+fn schema<T: JsonSchema>() -> serde_json::Value {
+    T::json_schema(&mut SchemaGenerator::default()).to_value()
+}
+
+#[test]
+fn test_double_schema() {
+    assert_eq!(
+        schema::<DoubleInfiniteNumber<i8>>(),
+        json!({
+            "oneOf": [
+                { "type": "integer", "format": "int8", "minimum": -128, "maximum": 127 },
+                { "const": "+infinity" },
+                { "const": "-infinity" },
+            ]
+        })
+    );
+    assert_eq!(
+        schema::<DoubleInfiniteNumber<i128>>(),
+        json!({
+            "oneOf": [
+                { "type": "integer", "format": "int128", "minimum": i64::MIN, "maximum": i64::MAX },
+                { "type": "string", "pattern": "^-?[0-9]+$" },
+                { "const": "+infinity" },
+                { "const": "-infinity" },
+            ]
+        })
+    );
+}
+
+#[test]
+fn test_single_schema() {
+    assert_eq!(
+        schema::<SingleInfiniteNumber<u64>>(),
+        json!({
+            "oneOf": [
+                { "type": "integer", "format": "uint64", "minimum": 0, "maximum": u64::MAX },
+                { "const": "+infinity" },
+            ]
+        })
+    );
+    assert_eq!(
+        schema::<SingleInfiniteNumber<u128>>(),
+        json!({
+            "oneOf": [
+                { "type": "integer", "format": "uint128", "minimum": 0, "maximum": u64::MAX },
+                { "type": "string", "pattern": "^[0-9]+$" },
+                { "const": "+infinity" },
+            ]
+        })
+    );
+}
+
+#[test]
+fn test_schema_names() {
+    assert_eq!(DoubleInfiniteNumber::<i32>::schema_name(), "DoubleInfiniteNumber_int32");
+    assert_eq!(SingleInfiniteNumber::<u16>::schema_name(), "SingleInfiniteNumber_uint16");
+    let root = schema_for!(DoubleInfiniteNumber<i16>).to_value();
+    assert_eq!(root["title"], "DoubleInfiniteNumber_int16");
+}
+
+// every serialized form matches exactly one branch of the schema
+#[test]
+fn test_schema_matches_serde_output() {
+    let schema = schema::<DoubleInfiniteNumber<i16>>();
+    let branches = schema["oneOf"].as_array().unwrap();
+    for x in [DoubleInfiniteNumber::NegInfinity, DoubleInfiniteNumber::new(i16::MIN), DoubleInfiniteNumber::new(i16::MAX), DoubleInfiniteNumber::PosInfinity] {
+        let value = serde_json::to_value(x).unwrap();
+        let matching = branches.iter().filter(|branch| match value.as_i64() {
+            Some(n) => branch["type"] == "integer" && branch["minimum"].as_i64().unwrap() <= n && n <= branch["maximum"].as_i64().unwrap(),
+            None => branch["const"] == value,
+        });
+        assert_eq!(matching.count(), 1, "{}", value);
+    }
+}
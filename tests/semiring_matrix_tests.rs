@@ -0,0 +1,190 @@
+use intfinity::{SingleInfiniteNumber, DoubleInfiniteNumber};
+use intfinity::matrix::{Matrix, SliceMatrix};
+use intfinity::semiring::Semiring;
+use intfinity::tropical::{MinPlus, MaxPlus, MaxMin};
+
+type Dist = MinPlus<SingleInfiniteNumber<u32>>;
+type SignedDist = MinPlus<DoubleInfiniteNumber<i32>>;
+type Width = MaxMin<SingleInfiniteNumber<u32>>;
+
+const INF: Dist = MinPlus(SingleInfiniteNumber::Infinity);
+
+fn d(v: u32) -> Dist {
+    MinPlus(SingleInfiniteNumber::new(v))
+}
+
+fn sd(v: i32) -> SignedDist {
+    MinPlus(DoubleInfiniteNumber::new(v))
+}
+
+fn w(v: u32) -> Width {
+    MaxMin(SingleInfiniteNumber::new(v))
+}
+
+#[test]
+fn test_semiring_identities() {
+    assert_eq!(<Dist as Semiring>::zero(), INF);
+    assert_eq!(<Dist as Semiring>::one(), d(0));
+    assert_eq!(<Width as Semiring>::zero(), w(0));
+    assert_eq!(<Width as Semiring>::one(), MaxMin(SingleInfiniteNumber::Infinity));
+    assert_eq!(<MaxMin<DoubleInfiniteNumber<i32>> as Semiring>::zero(), MaxMin(DoubleInfiniteNumber::NegInfinity));
+    assert_eq!(<MaxPlus<DoubleInfiniteNumber<i32>> as Semiring>::zero(), MaxPlus(DoubleInfiniteNumber::NegInfinity));
+    assert!(!<bool as Semiring>::zero());
+    assert!(<bool as Semiring>::one());
+}
+
+// `plus`/`times` do not clash with `Add`/`Mul`, so both work in method syntax
+#[test]
+fn test_semiring_operations_alongside_operators() {
+    use core::ops::{Add, Mul};
+
+    assert_eq!(d(3).plus(d(5)), d(3));
+    assert_eq!(d(3).times(d(5)), d(8));
+    assert_eq!(d(3).add(d(5)), d(3));
+    assert_eq!(d(3).mul(d(5)), d(8));
+    assert_eq!(w(3).plus(w(5)), w(5));
+    assert_eq!(w(3).times(w(5)), w(3));
+    assert!(true.plus(false) && !true.times(false));
+}
+
+#[test]
+fn test_semiring_stars() {
+    assert_eq!(Semiring::star(d(5)), Some(d(0)));
+    assert_eq!(Semiring::star(sd(5)), Some(sd(0)));
+    assert_eq!(Semiring::star(sd(-1)), Some(MinPlus(DoubleInfiniteNumber::NegInfinity)));
+    assert_eq!(Semiring::star(MaxPlus(DoubleInfiniteNumber::new(-1))), Some(MaxPlus(DoubleInfiniteNumber::new(0))));
+    assert_eq!(Semiring::star(MaxPlus(DoubleInfiniteNumber::new(1))), Some(MaxPlus(DoubleInfiniteNumber::<i32>::PosInfinity)));
+    assert_eq!(Semiring::star(w(3)), Some(MaxMin(SingleInfiniteNumber::Infinity)));
+    assert_eq!(Semiring::star(false), Some(true));
+}
+
+#[test]
+fn test_matrix_identity_and_mul() {
+    let a = Matrix::new([[d(0), d(4)], [d(1), INF]]);
+    assert_eq!(a * Matrix::identity(), a);
+    assert_eq!(Matrix::identity() * a, a);
+
+    let b = Matrix::new([[d(2)], [d(3)]]);
+    let c: Matrix<Dist, 2, 1> = a * b;
+    assert_eq!(c, Matrix::new([[d(2)], [d(3)]]));
+}
+
+#[test]
+fn test_matrix_add_and_index() {
+    let mut a = Matrix::new([[d(1), INF], [d(5), d(2)]]);
+    let b = Matrix::new([[d(3), d(7)], [INF, d(0)]]);
+    assert_eq!(a + b, Matrix::new([[d(1), d(7)], [d(5), d(0)]]));
+
+    a[(0, 1)] = d(9);
+    assert_eq!(a.get(0, 1), d(9));
+    assert_eq!(a[(1, 0)], d(5));
+}
+
+#[test]
+fn test_matrix_pow_counts_walks_of_fixed_length() {
+    // 0 -> 1 -> 2 -> 0 cycle with weights 1, 2, 3
+    let a = Matrix::new([
+        [INF, d(1), INF],
+        [INF, INF, d(2)],
+        [d(3), INF, INF],
+    ]);
+    assert_eq!(a.pow(0), Matrix::identity());
+    assert_eq!(a.pow(1), a);
+    let cube = a.pow(3);
+    assert_eq!(cube[(0, 0)], d(6));
+    assert_eq!(cube[(0, 1)], INF);
+    assert_eq!(a.pow(4)[(0, 1)], d(7));
+}
+
+#[test]
+fn test_matrix_star_is_all_pairs_shortest_paths() {
+    let a = Matrix::new([
+        [INF, d(4), d(1)],
+        [INF, INF, INF],
+        [INF, d(2), INF],
+    ]);
+    let s = a.star().unwrap();
+    assert_eq!(s, Matrix::new([
+        [d(0), d(3), d(1)],
+        [INF, d(0), INF],
+        [INF, d(2), d(0)],
+    ]));
+}
+
+#[test]
+fn test_matrix_star_negative_cycle() {
+    let inf = MinPlus(DoubleInfiniteNumber::PosInfinity);
+    let neg = MinPlus(DoubleInfiniteNumber::NegInfinity);
+    // 0 -> 1 -> 0 is a negative cycle, 2 is only reachable from it
+    let a = Matrix::new([
+        [inf, sd(1), inf],
+        [sd(-2), inf, sd(5)],
+        [inf, inf, inf],
+    ]);
+    let s = a.star().unwrap();
+    assert_eq!(s[(0, 0)], neg);
+    assert_eq!(s[(0, 2)], neg);
+    assert_eq!(s[(2, 0)], inf);
+    assert_eq!(s[(2, 2)], sd(0));
+}
+
+#[test]
+fn test_matrix_star_widest_path() {
+    let a = Matrix::new([
+        [w(0), w(5), w(2)],
+        [w(0), w(0), w(4)],
+        [w(0), w(0), w(0)],
+    ]);
+    let s = a.star().unwrap();
+    assert_eq!(s[(0, 2)], w(4));
+    assert_eq!(s[(0, 1)], w(5));
+    assert_eq!(s[(2, 0)], w(0));
+}
+
+#[test]
+fn test_matrix_star_transitive_closure() {
+    let a = Matrix::new([
+        [false, true, false],
+        [false, false, true],
+        [false, false, false],
+    ]);
+    let s = a.star().unwrap();
+    assert_eq!(s, Matrix::new([
+        [true, true, true],
+        [false, true, true],
+        [false, false, true],
+    ]));
+}
+
+#[test]
+fn test_slice_matrix_mul_pow_and_star() {
+    let mut a_data = [INF, d(1), INF, INF, INF, d(2), d(3), INF, INF];
+    let a = SliceMatrix::new(&mut a_data, 3, 3);
+
+    let mut out_data = [INF; 9];
+    let mut out = SliceMatrix::new(&mut out_data, 3, 3);
+    let mut scratch = [INF; 18];
+    a.pow_into(3, &mut out, &mut scratch);
+    assert_eq!(out.get(0, 0), d(6));
+    assert_eq!(out.get(1, 1), d(6));
+
+    let mut sq_data = [INF; 9];
+    let mut sq = SliceMatrix::new(&mut sq_data, 3, 3);
+    a.mul_into(&a, &mut sq);
+    assert_eq!(sq[(0, 2)], d(3));
+
+    let mut star_data = a_data;
+    let mut star = SliceMatrix::new(&mut star_data, 3, 3);
+    assert!(star.star_in_place());
+    assert_eq!(star.as_slice(), &[d(0), d(1), d(3), d(5), d(0), d(2), d(3), d(4), d(0)]);
+
+    star.fill_identity();
+    assert_eq!(star.as_slice(), &[d(0), INF, INF, INF, d(0), INF, INF, INF, d(0)]);
+}
+
+#[test]
+#[should_panic(expected = "slice length does not match matrix dimensions")]
+fn test_slice_matrix_rejects_wrong_length() {
+    let mut data = [d(0); 5];
+    let _ = SliceMatrix::new(&mut data, 2, 3);
+}
@@ -0,0 +1,48 @@
+use intfinity::{SingleInfiniteNumber, DoubleInfiniteNumber};
+
+#[test]
+fn test_serializes_integers_and_infinity_strings() {
+    let values = [DoubleInfiniteNumber::NegInfinity, DoubleInfiniteNumber::new(-5i32), DoubleInfiniteNumber::PosInfinity];
+    assert_eq!(serde_json::to_string(&values).unwrap(), r#"["-infinity",-5,"+infinity"]"#);
+    let values = [SingleInfiniteNumber::new(7u64), SingleInfiniteNumber::Infinity];
+    assert_eq!(serde_json::to_string(&values).unwrap(), r#"[7,"+infinity"]"#);
+}
+
+#[test]
+fn test_round_trips() {
+    for x in [DoubleInfiniteNumber::NegInfinity, DoubleInfiniteNumber::new(i64::MIN), DoubleInfiniteNumber::new(i64::MAX), DoubleInfiniteNumber::PosInfinity] {
+        let json = serde_json::to_string(&x).unwrap();
+        assert_eq!(serde_json::from_str::<DoubleInfiniteNumber<i64>>(&json).unwrap(), x);
+    }
+    for x in [SingleInfiniteNumber::new(0u8), SingleInfiniteNumber::new(u8::MAX), SingleInfiniteNumber::Infinity] {
+        let json = serde_json::to_string(&x).unwrap();
+        assert_eq!(serde_json::from_str::<SingleInfiniteNumber<u8>>(&json).unwrap(), x);
+    }
+}
+
+#[test]
+fn test_128_bit_round_trips() {
+    let values = [i128::MIN, i64::MIN as i128 - 1, i64::MIN as i128, i64::MAX as i128, i64::MAX as i128 + 1, i128::MAX];
+    for x in values.map(DoubleInfiniteNumber::new) {
+        let json = serde_json::to_string(&x).unwrap();
+        assert_eq!(serde_json::from_str::<DoubleInfiniteNumber<i128>>(&json).unwrap(), x);
+    }
+    for x in [0, u64::MAX as u128, u64::MAX as u128 + 1, u128::MAX].map(SingleInfiniteNumber::new) {
+        let json = serde_json::to_string(&x).unwrap();
+        assert_eq!(serde_json::from_str::<SingleInfiniteNumber<u128>>(&json).unwrap(), x);
+    }
+    assert_eq!(serde_json::to_string(&DoubleInfiniteNumber::new(i64::MAX as i128)).unwrap(), i64::MAX.to_string());
+    assert_eq!(serde_json::to_string(&SingleInfiniteNumber::new(u128::MAX)).unwrap(), format!("\"{}\"", u128::MAX));
+}
+
+#[test]
+fn test_rejects_invalid_input() {
+    assert!(serde_json::from_str::<DoubleInfiniteNumber<i8>>("128").is_err());
+    assert!(serde_json::from_str::<DoubleInfiniteNumber<i8>>(r#""infinity""#).is_err());
+    assert!(serde_json::from_str::<DoubleInfiniteNumber<i8>>("1.5").is_err());
+    assert!(serde_json::from_str::<SingleInfiniteNumber<u8>>("-1").is_err());
+    assert!(serde_json::from_str::<DoubleInfiniteNumber<i32>>(r#""5""#).is_err());
+    assert!(serde_json::from_str::<DoubleInfiniteNumber<i128>>(r#""5""#).is_err());
+    let err = serde_json::from_str::<SingleInfiniteNumber<u8>>(r#""-infinity""#).unwrap_err();
+    assert!(err.to_string().contains(r#"expected an integer or "+infinity""#));
+}
@@ -0,0 +1,60 @@
+use intfinity::{SingleInfiniteNumber, DoubleInfiniteNumber};
+use intfinity::arithmetic::InfiniteDifference;
+
+const INF: SingleInfiniteNumber<u32> = SingleInfiniteNumber::Infinity;
+
+fn s(v: u32) -> SingleInfiniteNumber<u32> {
+    SingleInfiniteNumber::new(v)
+}
+
+#[test]
+fn test_operator_clamps() {
+    assert_eq!(s(3) - s(5), s(0));
+    assert_eq!(s(3) - INF, s(0));
+    assert_eq!(INF - INF, INF);
+}
+
+#[test]
+fn test_checked_sub_reports_clamping() {
+    assert_eq!(s(3).checked_sub(s(5)), None);
+    assert_eq!(s(3).checked_sub(INF), None);
+    assert_eq!(s(5).checked_sub(s(3)), Some(s(2)));
+}
+
+#[test]
+fn test_abs_diff() {
+    assert_eq!(s(3).abs_diff(s(5)), s(2));
+    assert_eq!(s(5).abs_diff(s(3)), s(2));
+    assert_eq!(s(0).abs_diff(s(u32::MAX)), s(u32::MAX));
+    assert_eq!(s(3).abs_diff(INF), INF);
+    assert_eq!(INF.abs_diff(INF), INF);
+}
+
+#[test]
+fn test_signed_sub() {
+    assert_eq!(s(3).signed_sub(s(5)), DoubleInfiniteNumber::<i64>::new(-2));
+    assert_eq!(s(0).signed_sub(s(u32::MAX)), DoubleInfiniteNumber::new(-(u32::MAX as i64)));
+    assert_eq!(s(3).signed_sub(INF), DoubleInfiniteNumber::NegInfinity);
+    assert_eq!(INF.signed_sub(s(3)), DoubleInfiniteNumber::PosInfinity);
+    assert_eq!(SingleInfiniteNumber::new(0u64).signed_sub(SingleInfiniteNumber::new(u64::MAX)), DoubleInfiniteNumber::new(-(u64::MAX as i128)));
+    assert_eq!(SingleInfiniteNumber::new(1u8).signed_sub(SingleInfiniteNumber::new(255)), DoubleInfiniteNumber::<i16>::new(-254));
+}
+
+#[test]
+#[should_panic(expected = "indeterminate form: inf - inf")]
+fn test_signed_sub_of_infinities() {
+    let _ = INF.signed_sub(INF);
+}
+
+#[test]
+fn test_sub_with_infinity_policy() {
+    assert_eq!(INF.sub_with(INF, InfiniteDifference::Infinity), INF);
+    assert_eq!(s(3).sub_with(s(5), InfiniteDifference::Indeterminate), s(0));
+    assert_eq!(INF.sub_with(s(5), InfiniteDifference::Indeterminate), INF);
+}
+
+#[test]
+#[should_panic(expected = "indeterminate form: inf - inf")]
+fn test_sub_with_indeterminate_policy() {
+    let _ = INF.sub_with(INF, InfiniteDifference::Indeterminate);
+}
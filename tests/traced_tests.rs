@@ -0,0 +1,73 @@
+use std::mem::size_of;
+
+use intfinity::{SingleInfiniteNumber, DoubleInfiniteNumber};
+use intfinity::traced::{Op, Origin, Traced};
+
+type D = DoubleInfiniteNumber<i8>;
+
+fn t(v: i8) -> Traced<D> {
+    Traced::new(DoubleInfiniteNumber::new(v))
+}
+
+#[test]
+fn test_finite_values_have_no_origin() {
+    let x = t(3) + t(4);
+    assert_eq!(x.value(), DoubleInfiniteNumber::new(7));
+    assert_eq!(x.origin(), None);
+}
+
+#[test]
+fn test_input_infinity() {
+    let x = Traced::from(D::PosInfinity) + t(1);
+    assert_eq!(x.value(), D::PosInfinity);
+    assert_eq!(x.origin(), Some(Origin::Input));
+}
+
+#[test]
+fn test_overflow_records_operands() {
+    let x = t(100) + t(100);
+    assert_eq!(x.value(), D::PosInfinity);
+    assert_eq!(x.origin(), Some(Origin::Overflow { op: Op::Add, lhs: DoubleInfiniteNumber::new(100), rhs: DoubleInfiniteNumber::new(100) }));
+
+    let y = t(-16) * t(16);
+    assert_eq!(y.origin(), Some(Origin::Overflow { op: Op::Mul, lhs: DoubleInfiniteNumber::new(-16), rhs: DoubleInfiniteNumber::new(16) }));
+}
+
+#[test]
+fn test_origin_carries_through() {
+    let overflowed = t(100) + t(100);
+    let later = (overflowed * t(2)) - t(5);
+    assert_eq!(later.value(), D::PosInfinity);
+    assert_eq!(later.origin(), overflowed.origin());
+
+    // an infinity divided away loses its origin
+    assert_eq!((t(1) / overflowed).origin(), None);
+}
+
+#[test]
+fn test_division_origin() {
+    let x = t(-128) / t(-1);
+    assert_eq!(x.value(), D::PosInfinity);
+    assert_eq!(x.origin(), Some(Origin::Division { lhs: DoubleInfiniteNumber::new(-128), rhs: DoubleInfiniteNumber::new(-1) }));
+}
+
+#[test]
+fn test_single_overflow() {
+    let x = Traced::new(SingleInfiniteNumber::new(200u8)) + Traced::new(SingleInfiniteNumber::new(56));
+    assert!(matches!(x.origin(), Some(Origin::Overflow { op: Op::Add, .. })));
+}
+
+#[test]
+fn test_debug_shows_origin() {
+    let x = t(100) + t(100);
+    assert_eq!(
+        format!("{:?}", x.origin().unwrap()),
+        "Overflow { op: Add, lhs: Finite(100), rhs: Finite(100) }"
+    );
+}
+
+#[test]
+fn test_plain_types_keep_their_size() {
+    assert_eq!(size_of::<DoubleInfiniteNumber<i32>>(), 8);
+    assert_eq!(size_of::<SingleInfiniteNumber<u32>>(), 8);
+}
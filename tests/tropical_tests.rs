@@ -0,0 +1,76 @@
+use intfinity::{SingleInfiniteNumber, DoubleInfiniteNumber};
+use intfinity::tropical::{MinPlus, MaxPlus};
+
+type Dist = MinPlus<SingleInfiniteNumber<u32>>;
+type Score = MaxPlus<DoubleInfiniteNumber<i64>>;
+
+fn dist(v: u32) -> Dist {
+    MinPlus(SingleInfiniteNumber::new(v))
+}
+
+fn score(v: i64) -> Score {
+    MaxPlus(DoubleInfiniteNumber::new(v))
+}
+
+#[test]
+fn test_min_plus_add_is_min() {
+    assert_eq!(dist(3) + dist(5), dist(3));
+    assert_eq!(dist(3) + Dist::zero(), dist(3));
+}
+
+#[test]
+fn test_min_plus_mul_is_sum() {
+    assert_eq!(dist(3) * dist(5), dist(8));
+    assert_eq!(dist(3) * Dist::one(), dist(3));
+    assert_eq!(dist(u32::MAX) * dist(1), Dist::zero());
+}
+
+#[test]
+fn test_min_plus_zero_absorbs() {
+    assert_eq!(dist(3) * Dist::zero(), Dist::zero());
+
+    let pos = MinPlus(DoubleInfiniteNumber::<i32>::PosInfinity);
+    let neg = MinPlus(DoubleInfiniteNumber::<i32>::NegInfinity);
+    assert_eq!(pos * neg, MinPlus::zero());
+    assert_eq!(neg * pos, MinPlus::zero());
+}
+
+#[test]
+fn test_max_plus_add_is_max() {
+    assert_eq!(score(-3) + score(5), score(5));
+    assert_eq!(score(-3) + Score::zero(), score(-3));
+}
+
+#[test]
+fn test_max_plus_mul_is_sum() {
+    assert_eq!(score(-3) * score(5), score(2));
+    assert_eq!(score(-3) * Score::one(), score(-3));
+}
+
+#[test]
+fn test_max_plus_zero_absorbs() {
+    let pos = MaxPlus(DoubleInfiniteNumber::<i64>::PosInfinity);
+    assert_eq!(pos * Score::zero(), Score::zero());
+    assert_eq!(score(7) * Score::zero(), Score::zero());
+}
+
+#[test]
+fn test_sum_and_product() {
+    let path = [dist(1), dist(2), dist(4)];
+    assert_eq!(path.iter().copied().product::<Dist>(), dist(7));
+    assert_eq!(path.iter().copied().sum::<Dist>(), dist(1));
+    assert_eq!(core::iter::empty::<Dist>().sum::<Dist>(), Dist::zero());
+    assert_eq!(core::iter::empty::<Dist>().product::<Dist>(), Dist::one());
+
+    let scores = [score(-1), score(4)];
+    assert_eq!(scores.iter().copied().sum::<Score>(), score(4));
+    assert_eq!(scores.iter().copied().product::<Score>(), score(3));
+}
+
+#[test]
+fn test_shortest_path_relaxation() {
+    // best of two routes: 0 -> 1 -> 3 (1 + 6) and 0 -> 2 -> 3 (2 + inf)
+    let via_1 = dist(1) * dist(6);
+    let via_2 = dist(2) * Dist::zero();
+    assert_eq!(via_1 + via_2, dist(7));
+}
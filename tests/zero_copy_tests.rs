@@ -0,0 +1,43 @@
+use intfinity::{SingleInfiniteNumber, DoubleInfiniteNumber};
+use intfinity::bytes::{PackedI64, PackedU32, PackedU128};
+use zerocopy::{FromBytes, IntoBytes};
+
+#[test]
+fn test_bytemuck_cast_of_a_table() {
+    let table = [
+        PackedI64::new(DoubleInfiniteNumber::new(3)),
+        PackedI64::new(DoubleInfiniteNumber::PosInfinity),
+        PackedI64::new(DoubleInfiniteNumber::new(-9)),
+    ];
+    let bytes: &[u8] = bytemuck::cast_slice(&table);
+    assert_eq!(bytes.len(), 27);
+    assert_eq!(&bytes[..9], &DoubleInfiniteNumber::new(3i64).to_le_bytes());
+
+    let read: &[PackedI64] = bytemuck::cast_slice(bytes);
+    assert_eq!(read[1].get(), Some(DoubleInfiniteNumber::PosInfinity));
+    assert_eq!(<PackedI64 as bytemuck::Zeroable>::zeroed(), PackedI64::new(DoubleInfiniteNumber::new(0)));
+}
+
+#[test]
+fn test_zerocopy_reads_unaligned_bytes() {
+    let mut buffer = vec![0xffu8];
+    buffer.extend_from_slice(&SingleInfiniteNumber::new(42u32).to_le_bytes());
+    buffer.extend_from_slice(&SingleInfiniteNumber::<u32>::Infinity.to_le_bytes());
+
+    let table = <[PackedU32]>::ref_from_bytes(&buffer[1..]).unwrap();
+    assert_eq!(table[0].get(), Some(SingleInfiniteNumber::new(42)));
+    assert_eq!(table[1].get(), Some(SingleInfiniteNumber::Infinity));
+    assert_eq!(table.as_bytes(), &buffer[1..]);
+
+    let garbage = PackedU32::read_from_bytes(&[9, 0, 0, 0, 0]).unwrap();
+    assert_eq!(garbage.get(), None);
+}
+
+#[test]
+fn test_u128_casts() {
+    let bytes = SingleInfiniteNumber::new(u128::MAX).to_le_bytes();
+    let packed: &PackedU128 = bytemuck::from_bytes(&bytes);
+    assert_eq!(packed.get(), Some(SingleInfiniteNumber::new(u128::MAX)));
+    assert_eq!(PackedU128::ref_from_bytes(&bytes).unwrap(), packed);
+    assert_eq!(PackedU128::new(SingleInfiniteNumber::Infinity).as_bytes(), &SingleInfiniteNumber::<u128>::Infinity.to_le_bytes());
+}
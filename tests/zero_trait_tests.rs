@@ -33,7 +33,7 @@ impl Unsigned for UIntWrapper {}
 #[test]
 fn test_zero_for_finite_intfinity() {
     let zero_value = DoubleInfiniteNumber::new(0);
-    assert_eq!(zero_value.is_zero(), true);  
+    assert!(zero_value.is_zero());  
 }
 
 #[test]